@@ -1,209 +1,1563 @@
-use std::ops::{Coroutine, CoroutineState};
-use std::pin::Pin;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::FusedIterator;
+use std::sync::Arc;
 use arrayvec::ArrayVec;
-use crate::bits::Enchantments;
-use crate::dist::DIST;
+use crate::bits::{EnchantmentMask, Enchantments};
+use crate::constraints::{Constraint, ConstraintMasks, TargetSide};
 use crate::enchantments::Enchantment;
-use crate::items::ItemKind;
-use crate::dist::MAX_ITEMS;
+use crate::items::{Durability, ItemKind, PriorWork};
+use crate::rules::{Edition, GameMode, Rules};
+use crate::splits::{distinct_splits, positions, DistinctSplits, MAX_SPLIT_ITEMS};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CombinationError {
-    IncompatibleItems {
-        target: ItemKind,
-        sacrifice: ItemKind,
+    /// The sacrifice is neither a book, nor the same kind of item, nor a repair material of the target.
+    WrongItemKind {
+        target: Item,
+        sacrifice: Item,
+    },
+    /// None of the sacrifice's enchantments were transferred, because this one cannot be applied to the target.
+    NotApplicable {
+        target: Item,
+        sacrifice: Item,
+        enchantment: Enchantment,
+    },
+    /// None of the sacrifice's enchantments were transferred, because this one conflicts with one of the target.
+    ConflictingEnchantments {
+        target: Item,
+        sacrifice: Item,
+        enchantment: Enchantment,
+        conflicting: Enchantment,
+    },
+    /// The target already has this enchantment at the same level, which cannot be raised any further.
+    LevelAboveMaximum {
+        target: Item,
+        sacrifice: Item,
+        enchantment: Enchantment,
+    },
+    /// The anvil refuses the operation in survival mode.
+    TooExpensive {
+        target: Item,
+        sacrifice: Option<Item>,
+        cost: u16,
+    },
+    /// The operation would not change the target at all.
+    NoEffect {
+        target: Item,
+        sacrifice: Item,
+    },
+    /// The cost or the prior work penalty of the operation does not fit into a `u16`.
+    Overflow {
+        target: Item,
+        sacrifice: Option<Item>,
+    },
+    /// The operation costs more than the budget allows for a single step.
+    StepOverBudget {
+        target: Item,
+        sacrifice: Option<Item>,
+        cost: u16,
+        limit: u16,
+    },
+    /// Every plan that keeps to the budget for single steps costs more than the budget allows in total.
+    TotalOverBudget {
+        limit: u16,
+    },
+    /// The search cannot handle this many items.
+    TooManyItems {
+        count: usize,
+        limit: usize,
+    },
+    /// A constraint refers to an item that is not there.
+    NoSuchItem {
+        position: usize,
+        count: usize,
+    },
+    /// Every plan that combines the items breaks one of the constraints.
+    ConstraintsUnmet,
+}
+
+impl Display for CombinationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CombinationError::WrongItemKind { target, sacrifice } => {
+                write!(f, "{:?} cannot be combined with {:?}", target.kind, sacrifice.kind)
+            }
+            CombinationError::NotApplicable { target, enchantment, .. } => {
+                write!(f, "{enchantment} cannot be applied to {:?}", target.kind)
+            }
+            CombinationError::ConflictingEnchantments { enchantment, conflicting, .. } => {
+                write!(f, "{enchantment} conflicts with {conflicting}")
+            }
+            CombinationError::LevelAboveMaximum { enchantment, .. } => {
+                write!(f, "{enchantment} cannot be raised above level {}", enchantment.kind().max_level())
+            }
+            CombinationError::TooExpensive { cost, .. } => {
+                write!(f, "Too Expensive! The operation would cost {cost} levels")
+            }
+            CombinationError::NoEffect { target, sacrifice } => {
+                write!(f, "Combining {:?} with {:?} would have no effect", target.kind, sacrifice.kind)
+            }
+            CombinationError::Overflow { target, .. } => {
+                write!(f, "The cost or prior work penalty of working on {:?} is too large to count", target.kind)
+            }
+            CombinationError::StepOverBudget { cost, limit, .. } => {
+                write!(f, "The operation would cost {cost} levels, but a step may cost {limit} levels at most")
+            }
+            CombinationError::TotalOverBudget { limit } => {
+                write!(f, "Every plan costs more than the {limit} levels that may be spent in total")
+            }
+            CombinationError::TooManyItems { count, limit } => {
+                write!(f, "Cannot plan for {count} items, the limit is {limit}")
+            }
+            CombinationError::NoSuchItem { position, count } => {
+                write!(f, "A constraint refers to item {position}, but there are only {count} items")
+            }
+            CombinationError::ConstraintsUnmet => {
+                write!(f, "No plan keeps to the constraints")
+            }
+        }
     }
 }
 
-#[derive(Debug, Clone)]
+impl Error for CombinationError {}
+
+/// A single anvil operation. A step without a sacrifice only renames the target.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Step {
     pub target: Item,
-    pub sacrifice: Item,
+    pub sacrifice: Option<Item>,
     pub result: Item,
     pub cost: u16,
+    pub renamed: bool,
+}
+
+impl Step {
+    /// How many units of repair material this step consumes, if it is a material repair.
+    pub fn material_units(&self) -> u16 {
+        if self.sacrifice.is_some_and(|sacrifice| sacrifice.kind.is_repair_material()) {
+            (self.target.durability.damage - self.result.durability.damage)
+                .div_ceil(self.target.durability.max / 4)
+        } else {
+            0
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Step with cost {}{}:\n        {}\n", self.cost, if self.renamed { ", renaming" } else { "" }, self.target)?;
+
+        match self.sacrifice {
+            Some(material) if material.kind.is_repair_material() => {
+                write!(f, "        +\n        {}x {:?}\n", self.material_units(), material.kind)?;
+            }
+            Some(sacrifice) => write!(f, "        +\n        {sacrifice}\n")?,
+            None => {}
+        }
+
+        write!(f, "        =\n        {}", self.result)
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct Item {
     pub enchantments: Enchantments,
-    pub prior_work_penalty: u16,
+    pub prior_work: PriorWork,
     pub kind: ItemKind,
+    pub durability: Durability,
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} with {} anvil {} (pwp {})",
+            self.kind,
+            self.prior_work.uses(),
+            if self.prior_work.uses() == 1 { "use" } else { "uses" },
+            self.prior_work.penalty(),
+        )?;
+
+        if self.durability.max > 0 {
+            write!(f, " and {}/{} durability", self.durability.remaining(), self.durability.max)?;
+        }
+
+        write!(f, " with enchantments")?;
+
+        if self.enchantments.size() > 0 {
+            write!(f, " {}", self.enchantments)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Item {
-    pub fn combine(target: Item, sacrifice: Item) -> Result<Step, CombinationError> {
-        if target.kind != sacrifice.kind && sacrifice.kind != ItemKind::Book {
-            return Err(CombinationError::IncompatibleItems {
-                target: target.kind,
-                sacrifice: target.kind,
+    pub fn combine(target: Item, sacrifice: Item, rules: Rules) -> Result<Step, CombinationError> {
+        Item::limit(Item::merge(target, sacrifice, rules)?, rules)
+    }
+
+    /// Like [`Item::combine`], but without refusing steps that are too expensive.
+    pub(crate) fn merge(target: Item, sacrifice: Item, rules: Rules) -> Result<Step, CombinationError> {
+        if sacrifice.kind.is_repair_material() && !target.kind.is_repair_material() {
+            return Item::repair(target, sacrifice);
+        }
+
+        if target.kind.is_repair_material()
+            || sacrifice.kind != ItemKind::Book
+            && (target.kind != sacrifice.kind || target.durability.material != sacrifice.durability.material) {
+            return Err(CombinationError::WrongItemKind {
+                target,
+                sacrifice,
             });
         }
         
+        let prior_work = target.prior_work.max(sacrifice.prior_work).next();
+        let mut cost = target.prior_work.penalty() as u32 + sacrifice.prior_work.penalty() as u32;
+
         let mut step = Step {
             target,
-            sacrifice,
-            result: Item {
-                enchantments: target.enchantments,
-                prior_work_penalty: target.prior_work_penalty.max(sacrifice.prior_work_penalty) * 2 + 1,
-                kind: target.kind,
-            },
-            cost: target.prior_work_penalty + sacrifice.prior_work_penalty,
+            sacrifice: Some(sacrifice),
+            renamed: false,
+            result: target,
+            cost: 0,
         };
 
-        'se: for sacrifice_enchantment in sacrifice.enchantments.iter_contained() {
-            if !sacrifice_enchantment.kind().applicable_to().has(target.kind) {
-                continue;
+        if target.kind == sacrifice.kind && target.durability.damage > 0 {
+            // The sacrifice's remaining durability plus a bonus of 12% is transferred.
+            let repaired = sacrifice.durability.remaining() as u32 + target.durability.max as u32 * 12 / 100;
+            let damage = (target.durability.damage as u32).saturating_sub(repaired) as u16;
+
+            if damage < target.durability.damage {
+                step.result.durability.damage = damage;
+                cost += 2;
             }
-            
-            let mut target_enchantment_match_level = None;
-            
-            for target_enchantment in target.enchantments.iter_contained() {
-                if sacrifice_enchantment.kind().incompatible_with().has(target_enchantment.kind()) {
-                    step.cost += 1;
-                    break 'se;
-                }
-                
-                if target_enchantment.kind() == sacrifice_enchantment.kind() {
-                    target_enchantment_match_level = Some(target_enchantment.level());
-                    break;
-                }
+        }
+
+        let mut transferred_any = false;
+        let mut first_not_applicable = None;
+        let mut first_conflict = None;
+        let mut first_capped = None;
+        let mut result_kinds = EnchantmentMask::from(target.enchantments);
+
+        for sacrifice_enchantment in sacrifice.enchantments.iter_contained() {
+            let kind = sacrifice_enchantment.kind();
+
+            // Java Edition lets players in creative mode apply any enchantment to any item.
+            let mut applicable = kind.applicable_to(rules.edition).has(target.kind)
+                || rules.edition == Edition::Java && rules.game_mode == GameMode::Creative;
+
+            if !applicable {
+                first_not_applicable.get_or_insert(sacrifice_enchantment);
+            }
+
+            // In Java Edition, every conflict with an enchantment of the result so far costs one level.
+            let conflicts = kind.incompatible_with(rules.edition).remove(kind).intersection(result_kinds);
+
+            if let Some(conflicting) = conflicts.first() {
+                first_conflict.get_or_insert((sacrifice_enchantment, step.result.enchantments.get_enchantment(conflicting)));
+                applicable = false;
+                cost += (rules.edition == Edition::Java) as u32 * conflicts.count();
             }
-            
-            if let Some(mut result_enchantment_level) = target_enchantment_match_level {
-                if result_enchantment_level < sacrifice_enchantment.level() {
-                    result_enchantment_level = sacrifice_enchantment.level();
-                } else if result_enchantment_level == sacrifice_enchantment.level()
-                    && result_enchantment_level < sacrifice_enchantment.kind().max_level() {
-                    result_enchantment_level += 1;
-                }
 
-                step.cost += (if sacrifice.kind == ItemKind::Book {
-                    sacrifice_enchantment.kind().book_multiplier()
+            if !applicable {
+                continue;
+            }
+
+            transferred_any = true;
+
+            let target_level = step.result.enchantments.get_level(kind);
+            let result_level = if target_level == sacrifice_enchantment.level() {
+                if target_level < kind.max_level() {
+                    target_level + 1
                 } else {
-                    sacrifice_enchantment.kind().item_multiplier()
-                } * result_enchantment_level) as u16;
+                    first_capped.get_or_insert(sacrifice_enchantment);
+                    target_level
+                }
+            } else {
+                target_level.max(sacrifice_enchantment.level())
+            };
+
+            // Java Edition charges for the resulting level, Bedrock Edition only for the levels gained.
+            let charged_level = match rules.edition {
+                Edition::Java => result_level,
+                Edition::Bedrock => result_level - target_level,
+            };
 
-                step.result.enchantments = step.result.enchantments.set(Enchantment::new(
-                    sacrifice_enchantment.kind(),
-                    result_enchantment_level
-                ).unwrap());
+            cost += if sacrifice.kind == ItemKind::Book {
+                kind.book_multiplier()
             } else {
-                // There is no match.
+                kind.item_multiplier()
+            } as u32 * charged_level as u32;
 
-                step.cost += (if sacrifice.kind == ItemKind::Book {
-                    sacrifice_enchantment.kind().book_multiplier()
-                } else {
-                    sacrifice_enchantment.kind().item_multiplier()
-                } * sacrifice_enchantment.level()) as u16;
+            step.result.enchantments = step.result.enchantments.set(Enchantment::custom(kind, result_level));
+            result_kinds = result_kinds.add(kind);
+        }
+
+        // The anvil refuses to do anything if every enchantment was refused.
+        if !transferred_any {
+            if let Some((enchantment, conflicting)) = first_conflict {
+                return Err(CombinationError::ConflictingEnchantments {
+                    target,
+                    sacrifice,
+                    enchantment,
+                    conflicting,
+                });
+            }
+
+            if let Some(enchantment) = first_not_applicable {
+                return Err(CombinationError::NotApplicable {
+                    target,
+                    sacrifice,
+                    enchantment,
+                });
+            }
+        }
+
+        // A step that does not change anything is never part of a useful plan.
+        if step.result.enchantments == target.enchantments && step.result.durability == target.durability {
+            return Err(match first_capped {
+                Some(enchantment) => CombinationError::LevelAboveMaximum {
+                    target,
+                    sacrifice,
+                    enchantment,
+                },
+                None => CombinationError::NoEffect {
+                    target,
+                    sacrifice,
+                },
+            });
+        }
 
-                step.result.enchantments = step.result.enchantments.set(sacrifice_enchantment);
+        match (prior_work, u16::try_from(cost)) {
+            (Some(prior_work), Ok(cost)) => {
+                step.result.prior_work = prior_work;
+                step.cost = cost;
+                Ok(step)
             }
+            _ => Err(CombinationError::Overflow {
+                target,
+                sacrifice: Some(sacrifice),
+            }),
+        }
+    }
+
+    /// Combines the items and renames the result in the same operation, which costs one extra level.
+    pub fn combine_renamed(target: Item, sacrifice: Item, rules: Rules) -> Result<Step, CombinationError> {
+        let mut step = Item::combine(target, sacrifice, rules)?;
+        step.renamed = true;
+        step.cost = step.cost.checked_add(1).ok_or(CombinationError::Overflow {
+            target,
+            sacrifice: Some(sacrifice),
+        })?;
+        Item::limit(step, rules)
+    }
+
+    /// Only renames the item. This does not increase the prior work penalty.
+    pub fn rename(target: Item, rules: Rules) -> Result<Step, CombinationError> {
+        let mut cost = target.prior_work.penalty().checked_add(1).ok_or(CombinationError::Overflow {
+            target,
+            sacrifice: None,
+        })?;
+
+        // Java Edition never refuses an operation that only renames.
+        if rules.edition == Edition::Java {
+            if let Some(limit) = rules.game_mode.cost_limit() {
+                cost = cost.min(limit - 1);
+            }
+        }
+
+        Item::limit(Step {
+            target,
+            sacrifice: None,
+            result: target,
+            cost,
+            renamed: true,
+        }, rules)
+    }
+
+    fn repair(target: Item, material: Item) -> Result<Step, CombinationError> {
+        if target.durability.material != Some(material.kind) {
+            return Err(CombinationError::WrongItemKind {
+                target,
+                sacrifice: material,
+            });
+        }
+
+        // Each unit of material repairs a quarter of the maximum durability.
+        let repaired_per_unit = target.durability.max / 4;
+
+        if target.durability.damage == 0 || repaired_per_unit == 0 {
+            return Err(CombinationError::NoEffect {
+                target,
+                sacrifice: material,
+            });
+        }
+
+        let cost = target.prior_work.penalty() as u32
+            + material.prior_work.penalty() as u32
+            + target.durability.damage.div_ceil(repaired_per_unit) as u32;
+
+        let overflow = CombinationError::Overflow {
+            target,
+            sacrifice: Some(material),
+        };
+
+        Ok(Step {
+            target,
+            sacrifice: Some(material),
+            renamed: false,
+            result: Item {
+                prior_work: target.prior_work.max(material.prior_work).next().ok_or(overflow.clone())?,
+                durability: Durability {
+                    damage: 0,
+                    ..target.durability
+                },
+                ..target
+            },
+            cost: u16::try_from(cost).map_err(|_| overflow)?,
+        })
+    }
+
+    fn limit(step: Step, rules: Rules) -> Result<Step, CombinationError> {
+        if rules.game_mode.cost_limit().is_some_and(|limit| step.cost >= limit) {
+            return Err(CombinationError::TooExpensive {
+                target: step.target,
+                sacrifice: step.sacrifice,
+                cost: step.cost,
+            });
         }
         
         Ok(step)
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Branch {
-    pub steps: ArrayVec<Step, MAX_ITEMS>,
+    pub steps: Vec<Step>,
     pub total_cost: u16,
 }
 
 impl Branch {
-    pub fn of_two(first: Item, second: Item) -> Result<Self, CombinationError> {
-        let first_with_second = Item::combine(first, second);
-        let second_with_first = Item::combine(second, first);
-
-        let best_step = if let Ok(first) = first_with_second {
-            if let Ok(second) = second_with_first {
-                if first.cost < second.cost {
-                    first
-                } else {
-                    second
-                }
-            } else {
+    pub fn of_two(first: Item, second: Item, rules: Rules) -> Result<Self, CombinationError> {
+        let first_with_second = Item::combine(first, second, rules);
+        let second_with_first = Item::combine(second, first, rules);
+
+        let best_step = match (first_with_second, second_with_first) {
+            (Ok(first), Ok(second)) => if first.cost < second.cost {
                 first
-            }
-        } else {
-            second_with_first?
+            } else {
+                second
+            },
+            (Ok(step), Err(_)) | (Err(_), Ok(step)) => step,
+            // Prefer reporting the order that would have worked, had it not been too expensive.
+            (Err(error @ CombinationError::TooExpensive { .. }), Err(_)) | (Err(_), Err(error)) => return Err(error),
         };
 
         Ok(Branch {
             total_cost: best_step.cost,
-            steps: {
-                let mut steps = ArrayVec::new_const();
-                steps.push(best_step);
-                steps
-            },
+            steps: vec![best_step],
         })
     }
-}
 
-pub fn branch_iterator<'a>(items: &'a [Item]) -> impl Coroutine<Yield = Branch, Return = Result<(), CombinationError>> + 'a + Unpin {
-    Box::pin(#[coroutine] static || {
-        if items.len() == 1 {
-            yield Branch {
-                steps: ArrayVec::new_const(),
-                total_cost: 0,
+    /// The plans that merge two items with the item on `target_side` as the target, if their step
+    /// keeps to the budget for a single step.
+    ///
+    /// If either item can be the target, both orders are kept when they give different items, as
+    /// the more expensive step can still lead to a cheaper plan. Otherwise only the cheaper one is.
+    pub(crate) fn of_sides(
+        first: Item,
+        second: Item,
+        target_side: TargetSide,
+        rules: Rules,
+        options: SearchOptions,
+    ) -> Result<ArrayVec<Self, 2>, CombinationError> {
+        let merge = |target, sacrifice| {
+            let step = Item::combine(target, sacrifice, rules)?;
+            options.check_step(&step)?;
+            Ok(step)
+        };
+
+        let steps = match target_side {
+            TargetSide::Left => ArrayVec::from_iter([merge(first, second)?]),
+            TargetSide::Right => ArrayVec::from_iter([merge(second, first)?]),
+            TargetSide::Either => match (merge(first, second), merge(second, first)) {
+                (Ok(first), Ok(second)) if first.result == second.result => {
+                    ArrayVec::from_iter([if first.cost < second.cost { first } else { second }])
+                }
+                (Ok(first), Ok(second)) => ArrayVec::from([first, second]),
+                (Ok(step), Err(_)) | (Err(_), Ok(step)) => ArrayVec::from_iter([step]),
+                // Prefer reporting the order that would have worked, had it not been too expensive.
+                (Err(error @ (CombinationError::TooExpensive { .. } | CombinationError::StepOverBudget { .. })), Err(_))
+                | (Err(_), Err(error)) => return Err(error),
+            },
+        };
+
+        Ok(steps
+            .into_iter()
+            .map(|step| Branch {
+                total_cost: step.cost,
+                steps: vec![step],
+            })
+            .collect())
+    }
+
+    /// Puts the steps of the plans for the two merged items before the only step of this plan.
+    pub(crate) fn after(mut self, first: &Branch, second: &Branch) -> Result<Self, CombinationError> {
+        let last_step = self.steps.pop().unwrap();
+
+        self.total_cost = [first.total_cost, second.total_cost]
+            .into_iter()
+            .try_fold(self.total_cost, u16::checked_add)
+            .ok_or(CombinationError::Overflow {
+                target: last_step.target,
+                sacrifice: last_step.sacrifice,
+            })?;
+
+        self.steps.extend(first.steps.iter().cloned());
+        self.steps.extend(second.steps.iter().cloned());
+        self.steps.push(last_step);
+        Ok(self)
+    }
+
+    /// Renames the final item at the cheapest point of the plan. `item` is only used if the plan has
+    /// no steps, in which case it is the item to rename.
+    ///
+    /// Renaming during a step that works on the final item only costs one extra level, unless that
+    /// makes the step too expensive. Otherwise, the item is renamed on its own before any work is done.
+    pub fn renamed(self, item: Item, rules: Rules) -> Result<Self, CombinationError> {
+        self.renamed_within(item, rules, None)
+    }
+
+    /// Like [`Branch::renamed`], but no step may cost more than `max_step_cost`.
+    pub(crate) fn renamed_within(mut self, item: Item, rules: Rules, max_step_cost: Option<u16>) -> Result<Self, CombinationError> {
+        let mut current = self.steps.last().map_or(item, |step| step.target);
+        let mut candidates = Vec::new();
+
+        // Walk the steps that worked on the final item, starting from the last one.
+        if !self.steps.is_empty() {
+            candidates.push(self.steps.len() - 1);
+        }
+
+        for (index, step) in self.steps.iter().enumerate().rev().skip(1) {
+            if step.result == current {
+                candidates.push(index);
+                current = step.target;
+            }
+        }
+
+        for index in candidates.iter().rev().copied() {
+            let (Some(cost), Some(total_cost)) = (self.steps[index].cost.checked_add(1), self.total_cost.checked_add(1)) else {
+                continue;
             };
-            return Ok(());
+
+            if rules.game_mode.cost_limit().is_some_and(|limit| cost >= limit) || max_step_cost.is_some_and(|limit| cost > limit) {
+                continue;
+            }
+
+            self.steps[index].renamed = true;
+            self.steps[index].cost = cost;
+            self.total_cost = total_cost;
+            return Ok(self);
         }
-        
-        if items.len() == 2 {
-            yield Branch::of_two(items[0], items[1])?;
-            return Ok(());
-        }
-
-        for (left, right) in DIST[items.len() - 3].iter().copied() {
-            let mut left_items = ArrayVec::<_, MAX_ITEMS>::new_const();
-            for index in left.iter().copied() {
-                left_items.push(items[index as usize]);
-            }
-            
-            let left_has_one_item = left.len() == 1;
-            let mut left_branches_iter = branch_iterator(left_items.as_slice());
-            
-            let mut right_items = ArrayVec::<_, MAX_ITEMS>::new_const();
-            for index in right.iter().copied() {
-                right_items.push(items[index as usize]);
-            }
-            
-            loop {
-                match Pin::new(&mut left_branches_iter).resume(()) {
-                    CoroutineState::Yielded(left_branch) => {
-                        let mut right_branches_iter = branch_iterator(right_items.as_slice());
-                        
-                        loop {
-                            match Pin::new(&mut right_branches_iter).resume(()) {
-                                CoroutineState::Yielded(right_branch) => {
-                                    let first_item = if left_has_one_item {
-                                        left_items[0]
-                                    } else {
-                                        left_branch.steps.last().unwrap().result
-                                    };
-                                    
-                                    let second_item = right_branch.steps.last().unwrap().result;
-                                    
-                                    let mut new_branch = Branch::of_two(first_item, second_item)?;
-                                    
-                                    let last_step = new_branch.steps.pop().unwrap();
-                                    new_branch.steps.extend(left_branch.steps.iter().cloned());
-                                    new_branch.steps.extend(right_branch.steps.iter().cloned());
-                                    new_branch.steps.push(last_step);
-                                    new_branch.total_cost += left_branch.total_cost + right_branch.total_cost;
-                                    
-                                    yield new_branch;
-                                }
-                                CoroutineState::Complete(x) => break x?
+
+        let rename = Item::rename(current, rules)?;
+
+        if let Some(limit) = max_step_cost.filter(|&limit| rename.cost > limit) {
+            return Err(CombinationError::StepOverBudget {
+                target: current,
+                sacrifice: None,
+                cost: rename.cost,
+                limit,
+            });
+        }
+
+        self.total_cost = self.total_cost.checked_add(rename.cost).ok_or(CombinationError::Overflow {
+            target: current,
+            sacrifice: None,
+        })?;
+
+        self.steps.insert(0, rename);
+        Ok(self)
+    }
+}
+
+impl Display for Branch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Steps of branch with total cost {}:", self.total_cost)?;
+
+        for step in &self.steps {
+            write!(f, "\n{step}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct SearchOptions {
+    /// Rename the final item as part of every plan.
+    pub rename: bool,
+    /// The most levels a single step may cost, for players that cannot spend more at a time.
+    pub max_step_cost: Option<u16>,
+    /// The most levels all steps may cost together.
+    pub max_total_cost: Option<u16>,
+}
+
+impl SearchOptions {
+    /// Whether the plan keeps to the budget, and which part of it is exceeded if not.
+    pub fn check_budget(&self, branch: &Branch) -> Result<(), CombinationError> {
+        branch.steps.iter().try_for_each(|step| self.check_step(step))?;
+
+        match self.max_total_cost {
+            Some(limit) if branch.total_cost > limit => Err(CombinationError::TotalOverBudget { limit }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the step keeps to the budget for a single step.
+    pub(crate) fn check_step(&self, step: &Step) -> Result<(), CombinationError> {
+        match self.max_step_cost {
+            Some(limit) if step.cost > limit => Err(CombinationError::StepOverBudget {
+                target: step.target,
+                sacrifice: step.sacrifice,
+                cost: step.cost,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Enumerates every plan to merge the items into the first one that keeps to the budget of the
+/// options, followed by an error if there is no plan at all.
+///
+/// Equal items are interchangeable, so plans that only differ by which of them went where are
+/// enumerated once. Of the two orders of a step, only the cheaper one is enumerated, unless they
+/// give different items.
+///
+/// The number of plans grows faster than exponentially with the item count, so going through all
+/// of them is impractical beyond about 11 items, and never finishes at 20. The iterator only
+/// refuses more than 64 items, as it stores sets of items as bit masks, so the first plans of
+/// larger inputs are still available. To find a cheap plan for many items, use
+/// [`best_branch`](crate::best_branch), or [`greedy_branch`](crate::greedy_branch) beyond its limit.
+pub fn branch_iterator(items: &[Item], rules: Rules, options: SearchOptions) -> BranchIterator<'_> {
+    constrained_branch_iterator(items, rules, options, &[])
+}
+
+/// Enumerates the plans of [`branch_iterator`] that keep to the constraints, so the cheapest of
+/// them is the cheapest plan that does. Items are given to the constraints by their position.
+///
+/// If no plan keeps to the constraints, the error is why the plans that do failed, or
+/// [`CombinationError::ConstraintsUnmet`] if there are none.
+pub fn constrained_branch_iterator<'a>(items: &'a [Item], rules: Rules, options: SearchOptions, constraints: &[Constraint]) -> BranchIterator<'a> {
+    BranchIterator {
+        items,
+        rules,
+        options,
+        merges: MergeIterator::root(items, rules, options, constraints),
+        error: None,
+        found_any: false,
+        finished: false,
+    }
+}
+
+/// The iterator returned by [`branch_iterator`].
+pub struct BranchIterator<'a> {
+    items: &'a [Item],
+    rules: Rules,
+    options: SearchOptions,
+    merges: MergeIterator,
+    error: Option<CombinationError>,
+    found_any: bool,
+    finished: bool,
+}
+
+impl Iterator for BranchIterator<'_> {
+    type Item = Result<Branch, CombinationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            match self.merges.next() {
+                Some(Ok(branch)) => {
+                    let branch = if self.options.rename && !self.items.is_empty() {
+                        match branch.renamed_within(self.items[0], self.rules, self.options.max_step_cost) {
+                            Ok(branch) => branch,
+                            Err(new_error) => {
+                                keep_relevant_error(&mut self.error, new_error);
+                                continue;
                             }
                         }
+                    } else {
+                        branch
+                    };
+
+                    if let Err(new_error) = self.options.check_budget(&branch) {
+                        keep_relevant_error(&mut self.error, new_error);
+                        continue;
+                    }
+
+                    self.found_any = true;
+                    return Some(Ok(branch));
+                }
+                Some(Err(error)) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+                None => {
+                    self.finished = true;
+
+                    if !self.found_any {
+                        return Some(Err(self.error.take().unwrap_or(CombinationError::ConstraintsUnmet)));
                     }
-                    CoroutineState::Complete(x) => break x?
                 }
             }
         }
-        
-        Ok(())
-    })
-}
\ No newline at end of file
+
+        None
+    }
+}
+
+impl FusedIterator for BranchIterator<'_> {}
+
+/// Enumerates every way to merge the items, followed by an error if there is none.
+struct MergeIterator {
+    items: Vec<Item>,
+    /// The position of each item among all items, which the constraints refer to.
+    positions: Vec<u8>,
+    rules: Rules,
+    /// Steps above the budget for a single step are skipped right away, as no plan with them keeps to it.
+    options: SearchOptions,
+    constraints: Arc<ConstraintMasks>,
+    /// Whether the result of the items becomes the final result.
+    on_spine: bool,
+    /// Whether the items are all items, so the merge is the final step.
+    is_last: bool,
+    splits: DistinctSplits,
+    /// The items on each side of the current split, their positions, and which side is the target.
+    sides: (Vec<Item>, Vec<Item>),
+    side_positions: (Vec<u8>, Vec<u8>),
+    target_side: TargetSide,
+    left: Option<Box<MergeIterator>>,
+    /// The second order of the last merge, if both were kept.
+    pending: Option<Branch>,
+    /// The current way to merge the left items, and the ways to merge the right items after it.
+    right: Option<(Branch, Box<MergeIterator>)>,
+    error: Option<CombinationError>,
+    found_any: bool,
+    finished: bool,
+}
+
+impl MergeIterator {
+    /// Enumerates the ways to merge all items that keep to the constraints.
+    fn root(items: &[Item], rules: Rules, options: SearchOptions, constraints: &[Constraint]) -> Self {
+        let positions = (0..items.len().min(MAX_SPLIT_ITEMS) as u8).collect::<Vec<_>>();
+
+        // Too many items fail without a look at the constraints.
+        let constraints = if items.len() > MAX_SPLIT_ITEMS { Ok(Arc::default()) } else { ConstraintMasks::new(constraints, items.len()) };
+
+        match constraints {
+            Ok(constraints) => Self::new(items, &positions, rules, options, constraints, true, true),
+            Err(error) => {
+                let mut merges = Self::new(&[], &[], rules, options, Arc::default(), true, true);
+                merges.finished = true;
+                merges.error = Some(error);
+                merges
+            }
+        }
+    }
+
+    fn new(
+        items: &[Item],
+        positions: &[u8],
+        rules: Rules,
+        options: SearchOptions,
+        constraints: Arc<ConstraintMasks>,
+        on_spine: bool,
+        is_last: bool,
+    ) -> Self {
+        let error = (items.len() > MAX_SPLIT_ITEMS).then_some(CombinationError::TooManyItems {
+            count: items.len(),
+            limit: MAX_SPLIT_ITEMS,
+        });
+
+        // Constrained items are told apart from equal items, as swapping them breaks the constraints.
+        let keys = items
+            .iter()
+            .zip(positions)
+            .map(|(&item, &position)| (item, constraints.is_constrained(position).then_some(position)))
+            .collect::<Vec<_>>();
+
+        Self {
+            items: items.to_vec(),
+            positions: positions.to_vec(),
+            rules,
+            options,
+            splits: distinct_splits(&keys),
+            constraints,
+            on_spine,
+            is_last,
+            sides: (Vec::new(), Vec::new()),
+            side_positions: (Vec::new(), Vec::new()),
+            target_side: TargetSide::Either,
+            left: None,
+            pending: None,
+            right: None,
+            found_any: false,
+            finished: error.is_some(),
+            error,
+        }
+    }
+
+    /// The positions among all items of the items in `set`.
+    fn mask_of(&self, set: u64) -> u64 {
+        positions(set).fold(0, |mask, position| mask | 1 << self.positions[position])
+    }
+
+    /// Merges the results of two plans, where `left_item` is the left result if its plan has no steps.
+    fn merge(
+        left_item: Item,
+        left_branch: &Branch,
+        right_branch: &Branch,
+        target_side: TargetSide,
+        rules: Rules,
+        options: SearchOptions,
+    ) -> Result<ArrayVec<Branch, 2>, CombinationError> {
+        let first_item = left_branch.steps.last().map_or(left_item, |step| step.result);
+        let second_item = right_branch.steps.last().unwrap().result;
+
+        Branch::of_sides(first_item, second_item, target_side, rules, options)?
+            .into_iter()
+            .map(|branch| branch.after(left_branch, right_branch))
+            .collect()
+    }
+
+    /// Returns the first of the plans and keeps the other one in `pending` for the next call.
+    fn first_of(branches: ArrayVec<Branch, 2>, pending: &mut Option<Branch>) -> Branch {
+        let mut branches = branches.into_iter();
+        let first = branches.next().expect("a merge that succeeds has a plan");
+        *pending = branches.next();
+        first
+    }
+}
+
+impl Iterator for MergeIterator {
+    type Item = Result<Branch, CombinationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(branch) = self.pending.take() {
+            return Some(Ok(branch));
+        }
+
+        if self.finished {
+            return self.error.take().map(Err);
+        }
+
+        match *self.items {
+            [] | [_] => {
+                self.finished = true;
+
+                return Some(Ok(Branch {
+                    steps: Vec::new(),
+                    total_cost: 0,
+                }));
+            }
+            [first, second] => {
+                self.finished = true;
+
+                let target_side = self.constraints.target_side(1 << self.positions[0], 1 << self.positions[1], self.on_spine, self.is_last)?;
+                return Some(Branch::of_sides(first, second, target_side, self.rules, self.options).map(|branches| Self::first_of(branches, &mut self.pending)));
+            }
+            _ => {}
+        }
+
+        // Splits that cannot be combined are skipped; an error is only returned if no plan is left.
+        loop {
+            if let Some((left_branch, right_branches)) = &mut self.right {
+                match right_branches.next() {
+                    Some(Ok(right_branch)) => {
+                        match Self::merge(self.sides.0[0], left_branch, &right_branch, self.target_side, self.rules, self.options) {
+                            Ok(branches) => {
+                                self.found_any = true;
+                                return Some(Ok(Self::first_of(branches, &mut self.pending)));
+                            }
+                            Err(new_error) => keep_relevant_error(&mut self.error, new_error),
+                        }
+                    }
+                    Some(Err(new_error)) => keep_relevant_error(&mut self.error, new_error),
+                    None => self.right = None,
+                }
+            } else if let Some(left_branches) = &mut self.left {
+                match left_branches.next() {
+                    Some(Ok(left_branch)) => {
+                        let on_spine = self.on_spine && self.target_side == TargetSide::Right;
+                        let right_branches = MergeIterator::new(&self.sides.1, &self.side_positions.1, self.rules, self.options, self.constraints.clone(), on_spine, false);
+                        self.right = Some((left_branch, Box::new(right_branches)));
+                    }
+                    Some(Err(new_error)) => keep_relevant_error(&mut self.error, new_error),
+                    None => self.left = None,
+                }
+            } else if let Some((left, right)) = self.splits.next() {
+                let Some(target_side) = self.constraints.target_side(self.mask_of(left), self.mask_of(right), self.on_spine, self.is_last) else {
+                    continue;
+                };
+
+                self.target_side = target_side;
+                self.sides.0 = positions(left).map(|position| self.items[position]).collect();
+                self.sides.1 = positions(right).map(|position| self.items[position]).collect();
+                self.side_positions.0 = positions(left).map(|position| self.positions[position]).collect();
+                self.side_positions.1 = positions(right).map(|position| self.positions[position]).collect();
+
+                let on_spine = self.on_spine && self.target_side == TargetSide::Left;
+                let left_branches = MergeIterator::new(&self.sides.0, &self.side_positions.0, self.rules, self.options, self.constraints.clone(), on_spine, false);
+                self.left = Some(Box::new(left_branches));
+            } else {
+                self.finished = true;
+
+                if self.found_any {
+                    self.error = None;
+                }
+
+                return self.error.take().map(Err);
+            }
+        }
+    }
+}
+
+/// The distinct items and how often each of them occurs, in the order they first occur.
+pub fn item_counts(items: &[Item]) -> Vec<(Item, usize)> {
+    let mut counts = Vec::<(Item, usize)>::new();
+
+    for &item in items {
+        match counts.iter_mut().find(|(other, _)| *other == item) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((item, 1)),
+        }
+    }
+
+    counts
+}
+
+/// Keeps the first error, unless a later one shows that the items could have been combined.
+pub(crate) fn keep_relevant_error(error: &mut Option<CombinationError>, new_error: CombinationError) {
+    let could_combine = matches!(
+        new_error,
+        CombinationError::TooExpensive { .. } | CombinationError::StepOverBudget { .. } | CombinationError::TotalOverBudget { .. }
+    );
+
+    if error.is_none() || could_combine {
+        *error = Some(new_error);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::anvil::{branch_iterator, Branch, CombinationError, Item, SearchOptions};
+    use crate::bits::Enchantments;
+    use crate::enchantments::{Enchantment, EnchantmentKind};
+    use crate::items::{Durability, ItemKind, PriorWork};
+    use crate::rules::Rules;
+
+    pub(crate) fn item(kind: ItemKind, enchantments: &[(EnchantmentKind, u8)], prior_work_penalty: u16) -> Item {
+        Item {
+            enchantments: enchantments.iter().fold(Enchantments::empty(), |enchantments, &(kind, level)| {
+                enchantments.set(Enchantment::new(kind, level).unwrap())
+            }),
+            prior_work: PriorWork::from_penalty(prior_work_penalty).unwrap(),
+            kind,
+            durability: Durability::NONE,
+        }
+    }
+
+    pub(crate) fn book(kind: EnchantmentKind, level: u8) -> Item {
+        item(ItemKind::Book, &[(kind, level)], 0)
+    }
+
+    pub(crate) fn try_collect(items: &[Item], rules: Rules, options: SearchOptions) -> Result<Vec<Branch>, CombinationError> {
+        branch_iterator(items, rules, options).collect()
+    }
+
+    pub(crate) fn collect(items: &[Item], rules: Rules, options: SearchOptions) -> Vec<Branch> {
+        try_collect(items, rules, options).unwrap()
+    }
+
+    mod iterator {
+        use crate::anvil::{branch_iterator, item_counts, CombinationError, SearchOptions};
+        use crate::anvil::tests::{book, collect, item};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::ItemKind;
+        use crate::rules::Rules;
+        use crate::search::best_branch;
+
+        #[test]
+        fn error_after_last_plan() {
+            let items = [item(ItemKind::Boots, &[], 0), book(EnchantmentKind::Sharpness, 5), book(EnchantmentKind::Smite, 5)];
+            let mut branches = branch_iterator(&items, Rules::default(), SearchOptions::default());
+
+            assert!(matches!(branches.next(), Some(Err(_))));
+            assert!(branches.next().is_none());
+            assert!(branches.next().is_none());
+        }
+
+        #[test]
+        fn too_many_items() {
+            let items = vec![book(EnchantmentKind::Unbreaking, 1); 65];
+            let mut branches = branch_iterator(&items, Rules::default(), SearchOptions::default());
+
+            assert!(matches!(branches.next(), Some(Err(CombinationError::TooManyItems { count: 65, limit: 64 }))));
+            assert!(branches.next().is_none());
+        }
+
+        #[test]
+        fn equal_items_once() {
+            let boots = item(ItemKind::Boots, &[], 0);
+            let protection = book(EnchantmentKind::Protection, 3);
+            let items = [boots, protection, book(EnchantmentKind::Unbreaking, 3), protection, protection];
+            let branches = collect(&items, Rules::default(), SearchOptions::default());
+
+            assert!(!branches.is_empty());
+
+            for (index, branch) in branches.iter().enumerate() {
+                assert!(!branches[..index].contains(branch));
+            }
+
+            let cheapest = branches.iter().min_by_key(|branch| branch.total_cost).unwrap();
+            assert_eq!(cheapest.total_cost, best_branch(&items, Rules::default(), SearchOptions::default()).unwrap().total_cost);
+            assert_eq!(item_counts(&items), vec![(boots, 1), (protection, 3), (items[2], 1)]);
+        }
+
+        #[test]
+        fn step_budget() {
+            let items = [
+                item(ItemKind::Boots, &[], 0),
+                book(EnchantmentKind::Protection, 4),
+                book(EnchantmentKind::FeatherFalling, 4),
+                book(EnchantmentKind::Unbreaking, 3),
+                book(EnchantmentKind::Mending, 1),
+            ];
+
+            let options = SearchOptions {
+                max_step_cost: Some(12),
+                ..SearchOptions::default()
+            };
+
+            // Merges above the budget are skipped while merging, which leaves the same plans.
+            let expected = collect(&items, Rules::default(), SearchOptions::default())
+                .into_iter()
+                .filter(|branch| branch.steps.iter().all(|step| step.cost <= 12))
+                .collect::<Vec<_>>();
+
+            assert!(!expected.is_empty());
+            assert_eq!(collect(&items, Rules::default(), options), expected);
+        }
+    }
+
+    mod too_expensive {
+        use crate::anvil::{CombinationError, Item, SearchOptions};
+        use crate::anvil::tests::{book, collect, item};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::ItemKind;
+        use crate::rules::{Edition, GameMode, Rules};
+
+        const SURVIVAL: Rules = Rules::new(Edition::Java, GameMode::Survival);
+        const CREATIVE: Rules = Rules::new(Edition::Java, GameMode::Creative);
+
+        #[test]
+        fn combine() {
+            let protection = book(EnchantmentKind::Protection, 4);
+
+            assert_eq!(Item::combine(item(ItemKind::Boots, &[], 31), protection, SURVIVAL).unwrap().cost, 35);
+            assert!(matches!(
+                Item::combine(item(ItemKind::Boots, &[], 63), protection, SURVIVAL),
+                Err(CombinationError::TooExpensive { cost: 67, .. })
+            ));
+            assert_eq!(Item::combine(item(ItemKind::Boots, &[], 63), protection, CREATIVE).unwrap().cost, 67);
+        }
+
+        #[test]
+        fn prune_branches() {
+            let items = [
+                item(ItemKind::Boots, &[], 15),
+                book(EnchantmentKind::Protection, 4),
+                book(EnchantmentKind::FeatherFalling, 4),
+                book(EnchantmentKind::Unbreaking, 3),
+            ];
+
+            let survival = collect(&items, SURVIVAL, SearchOptions::default());
+            let creative = collect(&items, CREATIVE, SearchOptions::default());
+
+            assert!(!survival.is_empty());
+            assert!(survival.len() < creative.len());
+            assert!(survival.iter().all(|branch| branch.steps.iter().all(|step| step.cost < 40)));
+        }
+    }
+
+    mod overflow {
+        use crate::anvil::{Branch, CombinationError, Item};
+        use crate::anvil::tests::{book, item};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::ItemKind;
+        use crate::rules::{Edition, GameMode, Rules};
+
+        const CREATIVE: Rules = Rules::new(Edition::Java, GameMode::Creative);
+
+        #[test]
+        fn prior_work() {
+            let boots = item(ItemKind::Boots, &[], u16::MAX);
+
+            assert!(matches!(
+                Item::combine(boots, book(EnchantmentKind::Protection, 4), CREATIVE),
+                Err(CombinationError::Overflow { .. })
+            ));
+        }
+
+        #[test]
+        fn cost() {
+            let boots = item(ItemKind::Boots, &[], 32767);
+            let protection = item(ItemKind::Book, &[(EnchantmentKind::Protection, 4)], 32767);
+
+            assert!(matches!(Item::combine(boots, protection, CREATIVE), Err(CombinationError::Overflow { .. })));
+            assert_eq!(Item::combine(boots, book(EnchantmentKind::Protection, 4), CREATIVE).unwrap().cost, 32771);
+        }
+
+        #[test]
+        fn total_cost() {
+            let first = Branch::of_two(item(ItemKind::Boots, &[], 32767), book(EnchantmentKind::Protection, 4), CREATIVE).unwrap();
+            let second = Branch::of_two(item(ItemKind::Book, &[(EnchantmentKind::Unbreaking, 3)], 32767), book(EnchantmentKind::Mending, 1), CREATIVE).unwrap();
+            let last = Item::combine(first.steps[0].result, second.steps[0].result, CREATIVE);
+
+            assert!(matches!(last, Err(CombinationError::Overflow { .. })));
+            assert!(matches!(
+                Branch::of_two(item(ItemKind::Boots, &[], 0), book(EnchantmentKind::Protection, 4), CREATIVE).unwrap().after(&first, &second),
+                Err(CombinationError::Overflow { .. })
+            ));
+        }
+    }
+
+    mod edition {
+        use crate::anvil::{CombinationError, Item};
+        use crate::anvil::tests::{book, item};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::ItemKind;
+        use crate::rules::{Edition, GameMode, Rules};
+
+        const JAVA: Rules = Rules::new(Edition::Java, GameMode::Survival);
+        const BEDROCK: Rules = Rules::new(Edition::Bedrock, GameMode::Survival);
+
+        #[test]
+        fn sweeping_edge() {
+            let sword = item(ItemKind::Sword, &[], 0);
+            let axe = item(ItemKind::Axe, &[], 0);
+            let sweeping_edge = book(EnchantmentKind::SweepingEdge, 3);
+
+            assert!(Item::combine(sword, sweeping_edge, JAVA).unwrap().result.enchantments.has(EnchantmentKind::SweepingEdge));
+            assert!(matches!(Item::combine(axe, sweeping_edge, JAVA), Err(CombinationError::NotApplicable { .. })));
+            assert!(matches!(Item::combine(sword, sweeping_edge, BEDROCK), Err(CombinationError::NotApplicable { .. })));
+        }
+
+        #[test]
+        fn level_cost() {
+            let sword = item(ItemKind::Sword, &[(EnchantmentKind::Sharpness, 4), (EnchantmentKind::Looting, 3)], 0);
+
+            // Sharpness IV is raised to V, which costs all five levels in Java Edition but only one in
+            // Bedrock Edition. Looting III does not change, and is only charged for in Java Edition.
+            let sacrifice = book(EnchantmentKind::Sharpness, 4);
+            assert_eq!(Item::combine(sword, sacrifice, JAVA).unwrap().cost, 5);
+            assert_eq!(Item::combine(sword, sacrifice, BEDROCK).unwrap().cost, 1);
+
+            let sacrifice = item(ItemKind::Book, &[(EnchantmentKind::Sharpness, 4), (EnchantmentKind::Looting, 2)], 0);
+            assert_eq!(Item::combine(sword, sacrifice, JAVA).unwrap().cost, 5 + 2 * 3);
+            assert_eq!(Item::combine(sword, sacrifice, BEDROCK).unwrap().cost, 1);
+        }
+
+        #[test]
+        fn conflict_cost() {
+            let sword = item(ItemKind::Sword, &[(EnchantmentKind::Sharpness, 5)], 0);
+            let sacrifice = item(ItemKind::Book, &[(EnchantmentKind::Smite, 5), (EnchantmentKind::Looting, 1)], 0);
+
+            // Only Java Edition charges a level for the Smite that was dropped.
+            assert_eq!(Item::combine(sword, sacrifice, JAVA).unwrap().cost, 1 + 2);
+            assert_eq!(Item::combine(sword, sacrifice, BEDROCK).unwrap().cost, 2);
+        }
+
+        #[test]
+        fn same_applicability() {
+            for rules in [JAVA, BEDROCK] {
+                let trident = item(ItemKind::Trident, &[], 0);
+                let axe = item(ItemKind::Axe, &[], 0);
+
+                assert!(Item::combine(trident, book(EnchantmentKind::Impaling, 5), rules).is_ok());
+                assert!(Item::combine(axe, book(EnchantmentKind::Impaling, 5), rules).is_err());
+                assert!(Item::combine(axe, book(EnchantmentKind::Sharpness, 5), rules).is_ok());
+                assert!(Item::combine(axe, book(EnchantmentKind::Looting, 3), rules).is_err());
+            }
+        }
+
+        #[test]
+        fn mending_and_infinity() {
+            let bow = item(ItemKind::Bow, &[(EnchantmentKind::Infinity, 1)], 0);
+            let mending = book(EnchantmentKind::Mending, 1);
+
+            assert!(matches!(Item::combine(bow, mending, JAVA), Err(CombinationError::ConflictingEnchantments { .. })));
+            assert!(Item::combine(bow, mending, BEDROCK).unwrap().result.enchantments.has(EnchantmentKind::Mending));
+        }
+    }
+
+    mod repair {
+        use crate::anvil::{CombinationError, Item};
+        use crate::anvil::SearchOptions;
+        use crate::anvil::tests::{book, collect, item};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::{Durability, ItemKind};
+        use crate::rules::Rules;
+
+        fn diamond_boots(damage: u16) -> Item {
+            Item {
+                durability: Durability::new(damage, 429, Some(ItemKind::Diamond)).unwrap(),
+                ..item(ItemKind::Boots, &[(EnchantmentKind::Protection, 4)], 1)
+            }
+        }
+
+        #[test]
+        fn with_material() {
+            let step = Item::combine(diamond_boots(300), item(ItemKind::Diamond, &[], 0), Rules::default()).unwrap();
+
+            assert_eq!(step.material_units(), 3);
+            assert_eq!(step.cost, 1 + 3);
+            assert_eq!(step.result.durability.damage, 0);
+            assert_eq!(step.result.prior_work.penalty(), 3);
+            assert_eq!(step.result.enchantments, step.target.enchantments);
+
+            assert!(matches!(
+                Item::combine(diamond_boots(0), item(ItemKind::Diamond, &[], 0), Rules::default()),
+                Err(CombinationError::NoEffect { .. })
+            ));
+            assert!(matches!(
+                Item::combine(diamond_boots(300), item(ItemKind::IronIngot, &[], 0), Rules::default()),
+                Err(CombinationError::WrongItemKind { .. })
+            ));
+        }
+
+        #[test]
+        fn with_item() {
+            let step = Item::combine(diamond_boots(300), diamond_boots(200), Rules::default()).unwrap();
+
+            // 229 remaining durability plus 12% of 429.
+            assert_eq!(step.result.durability.damage, 300 - 229 - 51);
+            // Prior work, repair and Protection IV, which is already at the maximum and stays at IV.
+            assert_eq!(step.cost, 2 + 2 + 4);
+
+            // Without damage to repair, the merge does not change anything.
+            assert!(matches!(
+                Item::combine(diamond_boots(0), diamond_boots(200), Rules::default()),
+                Err(CombinationError::LevelAboveMaximum { .. })
+            ));
+        }
+
+        #[test]
+        fn large_durability() {
+            let damaged = |damage| Item {
+                durability: Durability::new(damage, 6000, None).unwrap(),
+                ..item(ItemKind::Boots, &[], 0)
+            };
+
+            let step = Item::combine(damaged(5000), damaged(4000), Rules::default()).unwrap();
+
+            // 2000 remaining durability plus 12% of 6000.
+            assert_eq!(step.result.durability.damage, 5000 - 2000 - 720);
+        }
+
+        #[test]
+        fn in_plan() {
+            let branches = collect(&[
+                diamond_boots(300),
+                item(ItemKind::Diamond, &[], 0),
+                book(EnchantmentKind::FeatherFalling, 4),
+            ], Rules::default(), SearchOptions::default());
+
+            assert!(!branches.is_empty());
+            assert!(branches.iter().all(|branch| branch.steps.last().unwrap().result.durability.damage == 0));
+        }
+    }
+
+    mod rename {
+        use crate::anvil::{Branch, Item, SearchOptions};
+        use crate::anvil::tests::{book, collect, item};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::ItemKind;
+        use crate::rules::{Edition, GameMode, Rules};
+
+        const RENAME: SearchOptions = SearchOptions {
+            rename: true,
+            max_step_cost: None,
+            max_total_cost: None,
+        };
+
+        #[test]
+        fn standalone() {
+            let sword = item(ItemKind::Sword, &[], 7);
+
+            let step = Item::rename(sword, Rules::default()).unwrap();
+            assert_eq!(step.cost, 8);
+            assert_eq!(step.result.prior_work.penalty(), 7);
+            assert!(step.sacrifice.is_none());
+
+            // Java Edition caps the cost of only renaming at 39 levels.
+            assert_eq!(Item::rename(item(ItemKind::Sword, &[], 63), Rules::default()).unwrap().cost, 39);
+            assert!(Item::rename(item(ItemKind::Sword, &[], 63), Rules::new(Edition::Bedrock, GameMode::Survival)).is_err());
+        }
+
+        #[test]
+        fn combined() {
+            let sword = item(ItemKind::Sword, &[], 0);
+            let sharpness = book(EnchantmentKind::Sharpness, 5);
+
+            let step = Item::combine_renamed(sword, sharpness, Rules::default()).unwrap();
+            assert_eq!(step.cost, Item::combine(sword, sharpness, Rules::default()).unwrap().cost + 1);
+            assert!(step.renamed);
+        }
+
+        #[test]
+        fn cheapest_point() {
+            let sword = item(ItemKind::Sword, &[], 31);
+            let sharpness = book(EnchantmentKind::Sharpness, 5);
+
+            // The merge costs 36 levels, so renaming during it is still possible.
+            let branch = Branch::of_two(sword, sharpness, Rules::default()).unwrap().renamed(sword, Rules::default()).unwrap();
+            assert_eq!(branch.steps.len(), 1);
+            assert_eq!(branch.total_cost, 37);
+
+            // The merge costs 39 levels, so the sword has to be renamed on its own first.
+            let sword = item(ItemKind::Sword, &[], 31);
+            let sharpness = item(ItemKind::Book, &[(EnchantmentKind::Sharpness, 5)], 3);
+            let branch = Branch::of_two(sword, sharpness, Rules::default()).unwrap().renamed(sword, Rules::default()).unwrap();
+            assert_eq!(branch.steps.len(), 2);
+            assert!(branch.steps[0].sacrifice.is_none());
+            assert_eq!(branch.total_cost, 39 + 32);
+        }
+
+        #[test]
+        fn in_plan() {
+            let items = [
+                item(ItemKind::Sword, &[], 0),
+                book(EnchantmentKind::Sharpness, 5),
+                book(EnchantmentKind::Looting, 3),
+            ];
+
+            let plain = collect(&items, Rules::default(), SearchOptions::default());
+            let renamed = collect(&items, Rules::default(), RENAME);
+
+            assert_eq!(plain.len(), renamed.len());
+            assert!(plain.iter().zip(renamed.iter()).all(|(plain, renamed)| plain.total_cost + 1 == renamed.total_cost));
+            assert!(renamed.iter().all(|branch| branch.steps.iter().filter(|step| step.renamed).count() == 1));
+        }
+    }
+
+    mod display {
+        use crate::anvil::{Branch, Item};
+        use crate::anvil::tests::{book, item};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::ItemKind;
+        use crate::rules::Rules;
+
+        #[test]
+        fn branch() {
+            let boots = item(ItemKind::Boots, &[(EnchantmentKind::DepthStrider, 3)], 1);
+            let branch = Branch::of_two(boots, book(EnchantmentKind::FeatherFalling, 4), Rules::default()).unwrap();
+
+            assert_eq!(branch.to_string(), "\
+Steps of branch with total cost 5:
+Step with cost 5:
+        Boots with 1 anvil use (pwp 1) with enchantments DepthStrider III
+        +
+        Book with 0 anvil uses (pwp 0) with enchantments FeatherFalling IV
+        =
+        Boots with 2 anvil uses (pwp 3) with enchantments DepthStrider III FeatherFalling IV");
+
+            let renamed = Item::rename(boots, Rules::default()).unwrap();
+
+            assert_eq!(renamed.to_string(), "\
+Step with cost 2, renaming:
+        Boots with 1 anvil use (pwp 1) with enchantments DepthStrider III
+        =
+        Boots with 1 anvil use (pwp 1) with enchantments DepthStrider III");
+        }
+    }
+
+    mod conflicts {
+        use crate::anvil::{CombinationError, Item, SearchOptions};
+        use crate::anvil::tests::{book, item, try_collect};
+        use crate::enchantments::EnchantmentKind;
+        use crate::items::ItemKind;
+        use crate::rules::{Edition, GameMode, Rules};
+
+        #[test]
+        fn skipped_individually() {
+            let boots = item(ItemKind::Boots, &[(EnchantmentKind::Protection, 4), (EnchantmentKind::DepthStrider, 3)], 0);
+            let sacrifice = item(ItemKind::Book, &[
+                (EnchantmentKind::FireProtection, 4),
+                (EnchantmentKind::FrostWalker, 2),
+                (EnchantmentKind::FeatherFalling, 4),
+                (EnchantmentKind::Sharpness, 5),
+            ], 0);
+
+            let step = Item::combine(boots, sacrifice, Rules::default()).unwrap();
+
+            // One level for each conflict and Feather Falling IV. Sharpness is not applicable and free.
+            assert_eq!(step.cost, 1 + 1 + 4);
+            assert!(step.result.enchantments.has(EnchantmentKind::FeatherFalling));
+            assert!(!step.result.enchantments.has(EnchantmentKind::FireProtection));
+            assert!(!step.result.enchantments.has(EnchantmentKind::FrostWalker));
+            assert!(!step.result.enchantments.has(EnchantmentKind::Sharpness));
+        }
+
+        #[test]
+        fn no_effect() {
+            let sword = item(ItemKind::Sword, &[(EnchantmentKind::Smite, 5)], 0);
+
+            assert!(matches!(
+                Item::combine(sword, book(EnchantmentKind::Sharpness, 5), Rules::default()),
+                Err(CombinationError::ConflictingEnchantments { .. })
+            ));
+            assert!(matches!(
+                Item::combine(sword, book(EnchantmentKind::Power, 5), Rules::default()),
+                Err(CombinationError::NotApplicable { .. })
+            ));
+            assert!(matches!(
+                Item::combine(sword, book(EnchantmentKind::Smite, 5), Rules::default()),
+                Err(CombinationError::LevelAboveMaximum { .. })
+            ));
+            assert!(matches!(
+                Item::combine(sword, item(ItemKind::Book, &[], 0), Rules::default()),
+                Err(CombinationError::NoEffect { .. })
+            ));
+
+            // Creative mode in Java Edition ignores applicability, but not conflicts.
+            let creative = Rules::new(Edition::Java, GameMode::Creative);
+            assert!(Item::combine(sword, book(EnchantmentKind::Power, 5), creative).is_ok());
+            assert!(Item::combine(sword, book(EnchantmentKind::Sharpness, 5), creative).is_err());
+        }
+
+        #[test]
+        fn useless_steps_rejected() {
+            let result = try_collect(&[
+                item(ItemKind::Sword, &[], 0),
+                book(EnchantmentKind::Sharpness, 5),
+                book(EnchantmentKind::Smite, 5),
+            ], Rules::default(), SearchOptions::default());
+
+            assert!(matches!(result, Err(CombinationError::ConflictingEnchantments { .. })));
+        }
+    }
+
+    mod diagnostics {
+        use crate::anvil::{CombinationError, Item};
+        use crate::anvil::tests::{book, item};
+        use crate::enchantments::{Enchantment, EnchantmentKind};
+        use crate::items::ItemKind;
+        use crate::rules::Rules;
+
+        #[test]
+        fn items_and_enchantments() {
+            let sword = item(ItemKind::Sword, &[(EnchantmentKind::Smite, 5)], 0);
+            let boots = item(ItemKind::Boots, &[], 0);
+            let sharpness = book(EnchantmentKind::Sharpness, 4);
+
+            assert_eq!(
+                Item::combine(sword, boots, Rules::default()).unwrap_err(),
+                CombinationError::WrongItemKind { target: sword, sacrifice: boots }
+            );
+            assert_eq!(
+                Item::combine(sword, sharpness, Rules::default()).unwrap_err(),
+                CombinationError::ConflictingEnchantments {
+                    target: sword,
+                    sacrifice: sharpness,
+                    enchantment: Enchantment::new(EnchantmentKind::Sharpness, 4).unwrap(),
+                    conflicting: Enchantment::new(EnchantmentKind::Smite, 5).unwrap(),
+                }
+            );
+        }
+
+        #[test]
+        fn display() {
+            let sword = item(ItemKind::Sword, &[(EnchantmentKind::Smite, 5)], 0);
+
+            assert_eq!(
+                Item::combine(sword, item(ItemKind::Boots, &[], 0), Rules::default()).unwrap_err().to_string(),
+                "Sword cannot be combined with Boots"
+            );
+            assert_eq!(
+                Item::combine(sword, book(EnchantmentKind::Sharpness, 4), Rules::default()).unwrap_err().to_string(),
+                "Sharpness IV conflicts with Smite V"
+            );
+            assert_eq!(
+                Item::combine(sword, book(EnchantmentKind::Power, 1), Rules::default()).unwrap_err().to_string(),
+                "Power I cannot be applied to Sword"
+            );
+            assert_eq!(
+                Item::combine(sword, book(EnchantmentKind::Smite, 5), Rules::default()).unwrap_err().to_string(),
+                "Smite V cannot be raised above level 5"
+            );
+            assert_eq!(
+                Item::combine(item(ItemKind::Sword, &[], 63), book(EnchantmentKind::Looting, 3), Rules::default()).unwrap_err().to_string(),
+                "Too Expensive! The operation would cost 69 levels"
+            );
+            assert_eq!(
+                Item::combine(sword, item(ItemKind::Book, &[], 0), Rules::default()).unwrap_err().to_string(),
+                "Combining Sword with Book would have no effect"
+            );
+        }
+    }
+
+    mod above_maximum {
+        use crate::anvil::{CombinationError, Item};
+        use crate::anvil::tests::item;
+        use crate::bits::Enchantments;
+        use crate::enchantments::{Enchantment, EnchantmentKind};
+        use crate::items::ItemKind;
+        use crate::rules::{Edition, GameMode, Rules};
+
+        fn custom_book(kind: EnchantmentKind, level: u8) -> Item {
+            Item {
+                enchantments: Enchantments::empty().set(Enchantment::custom(kind, level)),
+                ..item(ItemKind::Book, &[], 0)
+            }
+        }
+
+        #[test]
+        fn keeps_higher_level() {
+            let sword = item(ItemKind::Sword, &[(EnchantmentKind::Sharpness, 5)], 0);
+            let step = Item::combine(sword, custom_book(EnchantmentKind::Sharpness, 10), Rules::default()).unwrap();
+
+            assert_eq!(step.result.enchantments.get_level(EnchantmentKind::Sharpness), 10);
+            assert_eq!(step.cost, 10);
+
+            let sword = Item { enchantments: step.result.enchantments, ..sword };
+            let creative = Rules::new(Edition::Java, GameMode::Creative);
+            let step = Item::combine(sword, custom_book(EnchantmentKind::Looting, 255), creative).unwrap();
+
+            // Looting has a book multiplier of 2.
+            assert_eq!(step.cost, 510);
+            assert_eq!(step.result.enchantments.get_level(EnchantmentKind::Sharpness), 10);
+            assert_eq!(step.result.enchantments.get_level(EnchantmentKind::Looting), 255);
+        }
+
+        #[test]
+        fn never_increments() {
+            let sword = Item {
+                enchantments: Enchantments::empty().set(Enchantment::custom(EnchantmentKind::Sharpness, 10)),
+                ..item(ItemKind::Sword, &[], 0)
+            };
+
+            assert!(matches!(
+                Item::combine(sword, custom_book(EnchantmentKind::Sharpness, 10), Rules::default()),
+                Err(CombinationError::LevelAboveMaximum { .. })
+            ));
+            assert!(matches!(
+                Item::combine(sword, custom_book(EnchantmentKind::Sharpness, 6), Rules::default()),
+                Err(CombinationError::NoEffect { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+use arrayvec::ArrayVec;
+use crate::anvil::{keep_relevant_error, Branch, CombinationError, Item, SearchOptions};
+use crate::rules::Rules;
+use crate::search::{best_branch, check_item_count, search_limit, BoundedSearch, Goal, Outcome, MAX_SEARCH_ITEMS};
+
+/// How often the limit is asked for before the deadline is checked again.
+const DEADLINE_CHECK_INTERVAL: u32 = 256;
+
+/// Stops an [`anytime_best_branch`] search and holds the best plan it found so far. It can be
+/// shared with other threads while the search runs.
+#[derive(Default)]
+pub struct SearchControl {
+    cancelled: AtomicBool,
+    deadline: Option<Instant>,
+    best: Mutex<Option<Branch>>,
+}
+
+impl SearchControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A control that stops the search once `deadline` has passed.
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..Self::default()
+        }
+    }
+
+    /// Stops the search as soon as possible.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// The cheapest plan found so far, if any.
+    pub fn best(&self) -> Option<Branch> {
+        self.best.lock().unwrap().clone()
+    }
+
+    /// Keeps the plan if it is cheaper than the best one so far.
+    fn offer(&self, branch: Branch) {
+        let mut best = self.best.lock().unwrap();
+
+        if best.as_ref().is_none_or(|best| branch.total_cost < best.total_cost) {
+            *best = Some(branch);
+        }
+    }
+}
+
+/// How far an [`anytime_best_branch`] search has come.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// How many of the splits of all items were searched.
+    pub splits_done: usize,
+    pub splits: usize,
+    /// How many splits were searched and how many were skipped by their lower bound, at any depth.
+    pub nodes_visited: u64,
+    pub nodes_pruned: u64,
+}
+
+impl Progress {
+    /// The fraction of the splits of all items that were searched, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        if self.splits == 0 {
+            1.0
+        } else {
+            self.splits_done as f64 / self.splits as f64
+        }
+    }
+}
+
+/// The plan an [`anytime_best_branch`] search ended with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnytimeBranch {
+    /// The cheapest plan found, which is the plan of [`best_branch`] if the search is complete.
+    pub branch: Option<Branch>,
+    /// Whether the search ran to the end instead of being stopped.
+    pub complete: bool,
+}
+
+/// Searches for the plan of [`best_branch`] until it is found or `control` stops the search.
+/// `progress` is called after each split of all items.
+///
+/// The subset solver finds the cheapest plan before the search starts, so a plan is usually
+/// available once it is done. Stopping before then ends the search without a plan. Like for
+/// [`best_branch`], that plan is the result if the search finds none as cheap.
+pub fn anytime_best_branch(
+    items: &[Item],
+    rules: Rules,
+    options: SearchOptions,
+    control: &SearchControl,
+    progress: &mut dyn FnMut(Progress),
+) -> Result<AnytimeBranch, CombinationError> {
+    if items.len() < 3 {
+        let branch = best_branch(items, rules, options)?;
+        control.offer(branch.clone());
+        progress(Progress::default());
+
+        return Ok(AnytimeBranch {
+            branch: Some(branch),
+            complete: true,
+        });
+    }
+
+    check_item_count(items, MAX_SEARCH_ITEMS)?;
+
+    // Solving the subsets takes most of the time for some items, so it stops with the search.
+    let Some(search) = BoundedSearch::unless_stopped(items, rules, options, &|| control.is_stopped()) else {
+        return Ok(AnytimeBranch {
+            branch: control.best(),
+            complete: false,
+        });
+    };
+
+    if let Ok(optimal) = &search.optimal {
+        control.offer(optimal.clone());
+
+        return Ok(match search_splits(&search, optimal.total_cost as u32 + 1, control, progress) {
+            Ok(Some(branch)) => AnytimeBranch {
+                branch: Some(branch),
+                complete: true,
+            },
+            _ if control.is_stopped() => AnytimeBranch {
+                branch: control.best(),
+                complete: false,
+            },
+            _ => AnytimeBranch {
+                branch: Some(optimal.clone()),
+                complete: true,
+            },
+        });
+    }
+
+    match search_splits(&search, u32::MAX, control, progress)? {
+        Some(branch) => Ok(AnytimeBranch {
+            branch: Some(branch),
+            complete: true,
+        }),
+        None if !control.is_stopped() => Err(search.no_plan()),
+        None => Ok(AnytimeBranch {
+            branch: control.best(),
+            complete: false,
+        }),
+    }
+}
+
+/// The first of the cheapest plans below the limit, or `None` if there is none or the search was
+/// stopped.
+fn search_splits(
+    search: &BoundedSearch,
+    limit: u32,
+    control: &SearchControl,
+    progress: &mut dyn FnMut(Progress),
+) -> Result<Option<Branch>, CombinationError> {
+    let indices = (0..search.items.len() as u8).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+    let splits = search.splits(&indices).collect::<Vec<_>>();
+    let branch_limit = Cell::new(limit);
+    let calls = Cell::new(0_u32);
+    let stopped = Cell::new(false);
+    let mut best = None;
+    let mut outcome = Outcome::default();
+    let mut error = None;
+
+    // Once stopped, every split is skipped.
+    let limit = || {
+        if !stopped.get() {
+            calls.set(calls.get() + 1);
+
+            if calls.get().is_multiple_of(DEADLINE_CHECK_INTERVAL) && control.is_stopped() {
+                stopped.set(true);
+            }
+        }
+
+        if stopped.get() {
+            0
+        } else {
+            search_limit(branch_limit.get(), search.options)
+        }
+    };
+
+    for (index, &split) in splits.iter().enumerate() {
+        if control.is_stopped() {
+            return Ok(None);
+        }
+
+        let split_outcome = search.visit_split(&indices, split, true, Goal::Cheapest(1), &limit, &mut |branch| {
+            let branch = match search.renamed(branch) {
+                Ok(branch) => branch,
+                Err(new_error) => {
+                    keep_relevant_error(&mut error, new_error);
+                    return;
+                }
+            };
+
+            if (branch.total_cost as u32) < branch_limit.get() {
+                branch_limit.set(branch.total_cost as u32);
+                control.offer(branch.clone());
+                best = Some(branch);
+            }
+        });
+
+        outcome.extend(split_outcome);
+
+        progress(Progress {
+            splits_done: index + 1,
+            splits: splits.len(),
+            nodes_visited: search.visited.load(Ordering::Relaxed),
+            nodes_pruned: search.pruned.load(Ordering::Relaxed),
+        });
+    }
+
+    if stopped.get() {
+        return Ok(None);
+    }
+
+    outcome.into_result()?;
+
+    match error {
+        Some(error) if best.is_none() => Err(error),
+        _ => Ok(best),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use crate::anvil::SearchOptions;
+    use crate::anvil::tests::{book, item};
+    use crate::anytime::{anytime_best_branch, SearchControl};
+    use crate::enchantments::EnchantmentKind;
+    use crate::items::ItemKind;
+    use crate::optimal::optimal_branch;
+    use crate::rules::Rules;
+    use crate::search::best_branch;
+
+    #[test]
+    fn complete_and_stopped() {
+        let items = [
+            item(ItemKind::Boots, &[(EnchantmentKind::DepthStrider, 3)], 1),
+            book(EnchantmentKind::Protection, 4),
+            book(EnchantmentKind::FeatherFalling, 4),
+            book(EnchantmentKind::Thorns, 3),
+            book(EnchantmentKind::Unbreaking, 3),
+            book(EnchantmentKind::Mending, 1),
+        ];
+
+        let rules = Rules::default();
+        let options = SearchOptions::default();
+        let control = SearchControl::new();
+        let mut reports = Vec::new();
+
+        let result = anytime_best_branch(&items, rules, options, &control, &mut |progress| reports.push(progress)).unwrap();
+
+        assert!(result.complete);
+        assert_eq!(result.branch, Some(best_branch(&items, rules, options).unwrap()));
+        assert_eq!(control.best().map(|branch| branch.total_cost), result.branch.map(|branch| branch.total_cost));
+        assert_eq!(reports.last().unwrap().fraction(), 1.0);
+        assert!(reports.windows(2).all(|pair| pair[0].nodes_visited <= pair[1].nodes_visited));
+        assert!(reports.last().unwrap().nodes_pruned > 0);
+
+        // Stopped before the subsets are solved, so there is no plan yet.
+        let control = SearchControl::with_deadline(Instant::now());
+        let result = anytime_best_branch(&items, rules, options, &control, &mut |_| {}).unwrap();
+
+        assert!(!result.complete);
+        assert_eq!(result.branch, None);
+        assert_eq!(control.best(), None);
+
+        let control = SearchControl::new();
+        control.cancel();
+
+        assert!(!anytime_best_branch(&items, rules, options, &control, &mut |_| {}).unwrap().complete);
+    }
+
+    #[test]
+    fn best_only_improves() {
+        let items = [
+            item(ItemKind::Boots, &[(EnchantmentKind::Unbreaking, 1)], 0),
+            item(ItemKind::Boots, &[(EnchantmentKind::FrostWalker, 2)], 0),
+            item(ItemKind::Book, &[(EnchantmentKind::FrostWalker, 2), (EnchantmentKind::FeatherFalling, 4)], 1),
+            item(ItemKind::Book, &[(EnchantmentKind::Protection, 2), (EnchantmentKind::FrostWalker, 1)], 0),
+            item(ItemKind::Book, &[(EnchantmentKind::FireProtection, 3), (EnchantmentKind::FeatherFalling, 2)], 0),
+        ];
+
+        let control = SearchControl::new();
+        let mut costs = Vec::new();
+
+        let result = anytime_best_branch(&items, Rules::default(), SearchOptions::default(), &control, &mut |_| {
+            costs.push(control.best().unwrap().total_cost);
+        }).unwrap();
+
+        assert!(result.complete);
+        assert_eq!(result.branch.unwrap().total_cost, optimal_branch(&items, Rules::default(), SearchOptions::default()).unwrap().total_cost);
+        assert!(costs.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(costs.last(), Some(&27));
+    }
+
+    #[test]
+    fn stopped_while_solving() {
+        let items = [item(ItemKind::Helmet, &[], 0)]
+            .into_iter()
+            .chain([
+                EnchantmentKind::Protection,
+                EnchantmentKind::Respiration,
+                EnchantmentKind::Unbreaking,
+                EnchantmentKind::Thorns,
+            ].repeat(4).into_iter().map(|kind| book(kind, 1)))
+            .collect::<Vec<_>>();
+
+        let start = Instant::now();
+        let control = SearchControl::with_deadline(start + Duration::from_millis(100));
+        let result = anytime_best_branch(&items, Rules::default(), SearchOptions::default(), &control, &mut |_| {}).unwrap();
+
+        assert!(!result.complete);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,10 +1,10 @@
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::mem::transmute;
 use crate::enchantments::{Enchantment, EnchantmentKind};
 use crate::items::ItemKind;
 
 #[derive(Clone, Copy, PartialEq)]
-pub struct ItemMask(u32);
+pub struct ItemMask(u64);
 
 impl Debug for ItemMask {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -34,22 +34,32 @@ impl ItemMask {
 
     #[inline]
     pub const fn has(self, kind: ItemKind) -> bool {
-        self.0 & (1 << kind as u32) != 0
+        self.0 & (1 << kind as u64) != 0
     }
 
     #[inline]
     pub const fn add(self, kind: ItemKind) -> Self {
-        Self(self.0 | (1 << kind as u32))
+        Self(self.0 | (1 << kind as u64))
     }
 
     #[inline]
     pub const fn remove(self, kind: ItemKind) -> Self {
-        Self(self.0 & !(1 << kind as u32))
+        Self(self.0 & !(1 << kind as u64))
+    }
+
+    #[inline]
+    pub const fn all() -> Self {
+        Self(u64::MAX)
+    }
+
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
     }
     
     pub fn iter(self) -> impl Iterator<Item = (ItemKind, bool)> {
         struct Iter {
-            raw_mask: u32,
+            raw_mask: u64,
             index: u8,
         }
         
@@ -61,7 +71,7 @@ impl ItemMask {
                     None
                 } else {
                     let contained = (self.raw_mask >> self.index as u32) & 1 == 1;
-                    let item = unsafe { transmute(self.index) };
+                    let item = unsafe { transmute::<u8, ItemKind>(self.index) };
                     self.index += 1;
                     Some((item, contained))
                 }
@@ -75,6 +85,7 @@ impl ItemMask {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct EnchantmentMask(u64);
 
 impl EnchantmentMask {
@@ -110,6 +121,44 @@ impl EnchantmentMask {
     pub const fn remove(self, kind: EnchantmentKind) -> Self {
         Self(self.0 & !(1 << kind as u64))
     }
+
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    pub const fn count(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// The enchantment of the mask that comes first in the order of [`EnchantmentKind`].
+    #[inline]
+    pub fn first(self) -> Option<EnchantmentKind> {
+        (self.0 != 0).then(|| unsafe { transmute::<u8, EnchantmentKind>(self.0.trailing_zeros() as u8) })
+    }
+
+    /// The enchantments of the mask, in the order of [`EnchantmentKind`].
+    #[inline]
+    pub fn kinds(self) -> impl Iterator<Item = EnchantmentKind> {
+        let mut mask = self;
+
+        std::iter::from_fn(move || {
+            let kind = mask.first()?;
+            mask = mask.remove(kind);
+            Some(kind)
+        })
+    }
     
     pub fn iter(self) -> impl Iterator<Item = (EnchantmentKind, bool)> {
         struct Iter {
@@ -125,7 +174,7 @@ impl EnchantmentMask {
                     None
                 } else {
                     let contained = (self.raw_mask >> self.index as u32) & 1 == 1;
-                    let item = unsafe { transmute(self.index) };
+                    let item = unsafe { transmute::<u8, EnchantmentKind>(self.index) };
                     self.index += 1;
                     Some((item, contained))
                 }
@@ -139,8 +188,9 @@ impl EnchantmentMask {
     }
 }
 
+/// The level of every enchantment, where 0 means that the enchantment is not present.
 #[derive(Copy, Clone, PartialEq)]
-pub struct Enchantments(u128);
+pub struct Enchantments([u8; EnchantmentKind::COUNT]);
 
 impl Debug for Enchantments {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -149,31 +199,45 @@ impl Debug for Enchantments {
     }
 }
 
+impl Display for Enchantments {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, enchantment) in self.iter_contained().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{enchantment}")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Enchantments {
     #[inline]
     pub const fn empty() -> Self {
-        Enchantments(0)
+        Enchantments([0; EnchantmentKind::COUNT])
     }
 
     #[inline]
-    pub fn set(self, enchantment: Enchantment) -> Self {
-        let factor = enchantment.kind() as u128 * 3;
-        Self((self.0 & !(7 << factor)) | ((enchantment.level() as u128) << factor))
+    pub const fn set(mut self, enchantment: Enchantment) -> Self {
+        self.0[enchantment.kind() as usize] = enchantment.level();
+        self
     }
 
     #[inline]
     pub const fn get_level(self, kind: EnchantmentKind) -> u8 {
-        ((self.0 >> (kind as u128 * 3)) & 7) as u8
+        self.0[kind as usize]
     }
 
     #[inline]
     pub const fn get_enchantment(self, kind: EnchantmentKind) -> Enchantment {
-        Enchantment::new(kind, self.get_level(kind)).unwrap()
+        Enchantment::custom(kind, self.get_level(kind))
     }
 
     #[inline]
     pub const fn has(self, kind: EnchantmentKind) -> bool {
-        self.0 & (7 << (kind as u128 * 3)) != 0
+        self.0[kind as usize] != 0
     }
     
     #[inline]
@@ -181,8 +245,8 @@ impl Enchantments {
         let mut count = 0_u8;
         let mut i = 0;
         
-        while i < 42 {
-            if (self.0 >> (i * 3)) & 7 != 0 {
+        while i < EnchantmentKind::COUNT {
+            if self.0[i] != 0 {
                 count += 1;
             }
             
@@ -193,13 +257,23 @@ impl Enchantments {
     }
     
     #[inline]
-    pub const fn raw(self) -> u128 {
+    pub const fn levels(self) -> [u8; EnchantmentKind::COUNT] {
         self.0
     }
 
+    /// The higher level of every enchantment in either.
+    #[inline]
+    pub(crate) fn union(mut self, other: Self) -> Self {
+        for (level, other_level) in self.0.iter_mut().zip(other.0) {
+            *level = (*level).max(other_level);
+        }
+
+        self
+    }
+
     #[inline]
     pub fn iter_contained(self) -> impl Iterator<Item = Enchantment> {
-        self.iter().filter(|e| e.level() > 0)
+        EnchantmentMask::from(self).kinds().map(move |kind| self.get_enchantment(kind))
     }
     
     pub fn iter(self) -> impl Iterator<Item = Enchantment> {
@@ -215,10 +289,10 @@ impl Enchantments {
                 if self.index > EnchantmentKind::LAST as u8 {
                     None
                 } else {
-                    let kind = unsafe { transmute(self.index) };
+                    let kind = unsafe { transmute::<u8, EnchantmentKind>(self.index) };
                     let level = self.e.get_level(kind);
                     self.index += 1;
-                    unsafe { Some(Enchantment::new(kind, level).unwrap_unchecked()) }
+                    Some(Enchantment::custom(kind, level))
                 }
             }
         }
@@ -227,11 +301,16 @@ impl Enchantments {
     }
 }
 
-impl Into<EnchantmentMask> for Enchantments {
-    fn into(self) -> EnchantmentMask {
-        self.iter()
-            .filter_map(|e| (e.level() > 0).then_some(e.kind()))
-            .fold(EnchantmentMask::empty(), |mask, kind| mask.add(kind))
+impl From<Enchantments> for EnchantmentMask {
+    #[inline]
+    fn from(enchantments: Enchantments) -> Self {
+        let mut mask = 0;
+
+        for (index, level) in enchantments.0.into_iter().enumerate() {
+            mask |= ((level > 0) as u64) << index;
+        }
+
+        EnchantmentMask(mask)
     }
 }
 
@@ -281,4 +360,22 @@ mod tests {
             );
         }
     }
+
+    mod enchantments {
+        use crate::bits::Enchantments;
+        use crate::enchantments::{Enchantment, EnchantmentKind};
+
+        #[test]
+        fn levels_above_maximum() {
+            let enchantments = Enchantments::empty()
+                .set(Enchantment::custom(EnchantmentKind::Efficiency, 10))
+                .set(Enchantment::custom(EnchantmentKind::Unbreaking, 255));
+
+            assert_eq!(enchantments.get_level(EnchantmentKind::Efficiency), 10);
+            assert_eq!(enchantments.get_level(EnchantmentKind::Unbreaking), 255);
+            assert_eq!(enchantments.size(), 2);
+            assert!(enchantments.get_enchantment(EnchantmentKind::Efficiency).is_above_maximum());
+            assert_eq!(enchantments.to_string(), "Unbreaking CCLV Efficiency X");
+        }
+    }
 }
\ No newline at end of file
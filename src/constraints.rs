@@ -0,0 +1,224 @@
+use std::sync::Arc;
+use crate::anvil::CombinationError;
+
+/// A requirement on how the items are merged, for players that want some of them applied in a
+/// certain way. Items are given by their position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Constraint {
+    /// The items are merged with each other before any other item is added, like books that are
+    /// stored merged already.
+    MergeTogether(Vec<usize>),
+    /// The item is applied on its own to the item that becomes the result, not to another sacrifice.
+    ApplyToTarget(usize),
+    /// The item is applied on its own in the last step.
+    ApplyLast(usize),
+}
+
+/// The constraints as bit masks of positions, checked for every split while enumerating plans.
+#[derive(Debug, Default)]
+pub(crate) struct ConstraintMasks {
+    together: Vec<u64>,
+    to_target: u64,
+    last: u64,
+    /// The positions that any constraint refers to, which cannot be swapped with equal items.
+    constrained: u64,
+}
+
+impl ConstraintMasks {
+    pub(crate) fn new(constraints: &[Constraint], count: usize) -> Result<Arc<Self>, CombinationError> {
+        let mut masks = ConstraintMasks::default();
+
+        let mask_of = |position: usize| {
+            if position < count {
+                Ok(1 << position)
+            } else {
+                Err(CombinationError::NoSuchItem { position, count })
+            }
+        };
+
+        for constraint in constraints {
+            match constraint {
+                Constraint::MergeTogether(positions) => {
+                    let together = positions.iter().try_fold(0, |mask, &position| Ok(mask | mask_of(position)?))?;
+                    masks.constrained |= together;
+
+                    // A single item is always merged on its own.
+                    if together.count_ones() > 1 {
+                        masks.together.push(together);
+                    }
+                }
+                &Constraint::ApplyToTarget(position) => {
+                    masks.to_target |= mask_of(position)?;
+                    masks.constrained |= masks.to_target;
+                }
+                &Constraint::ApplyLast(position) => {
+                    masks.last |= mask_of(position)?;
+                    masks.constrained |= masks.last;
+                }
+            }
+        }
+
+        Ok(Arc::new(masks))
+    }
+
+    pub(crate) fn is_constrained(&self, position: u8) -> bool {
+        self.constrained & (1 << position) != 0
+    }
+
+    /// Which side of a split becomes the target of the step that merges them, or `None` if the
+    /// split breaks the constraints. The result of the split's items becomes the final result if
+    /// `on_spine`, and the step is the final one if `is_last`.
+    pub(crate) fn target_side(&self, left: u64, right: u64, on_spine: bool, is_last: bool) -> Option<TargetSide> {
+        let all = left | right;
+
+        if self.together.iter().any(|&together| together & left != 0 && together & right != 0 && together != all) {
+            return None;
+        }
+
+        if is_last && self.last != 0 && left != self.last && right != self.last {
+            return None;
+        }
+
+        let applied = self.to_target | self.last;
+
+        if applied & all == 0 {
+            return Some(TargetSide::Either);
+        }
+
+        // Items applied to the target cannot be merged into anything that does not become the result.
+        if !on_spine {
+            return None;
+        }
+
+        // A side with such an item is the target if it holds more items, and the sacrifice otherwise.
+        let is_target = |side: u64| side & applied != 0 && side.count_ones() > 1;
+        let is_sacrifice = |side: u64| side & applied != 0 && side.count_ones() == 1;
+
+        match (is_target(left) || is_sacrifice(right), is_target(right) || is_sacrifice(left)) {
+            (true, false) => Some(TargetSide::Left),
+            (false, true) => Some(TargetSide::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Which side of a split becomes the target when the sides are merged.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TargetSide {
+    /// Both orders if they give different items, otherwise the cheaper one.
+    Either,
+    Left,
+    Right,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::anvil::tests::{book, item};
+    use crate::anvil::{constrained_branch_iterator, Branch, CombinationError, Item, SearchOptions};
+    use crate::constraints::Constraint;
+    use crate::enchantments::EnchantmentKind;
+    use crate::items::ItemKind;
+    use crate::rules::Rules;
+    use crate::search::{best_branch, constrained_best_branch};
+
+    fn items() -> [Item; 5] {
+        [
+            item(ItemKind::Sword, &[], 0),
+            book(EnchantmentKind::Sharpness, 5),
+            book(EnchantmentKind::Looting, 3),
+            book(EnchantmentKind::Unbreaking, 3),
+            book(EnchantmentKind::Mending, 1),
+        ]
+    }
+
+    fn cheapest(items: &[Item], constraints: &[Constraint]) -> Result<Vec<Branch>, CombinationError> {
+        let branches = constrained_branch_iterator(items, Rules::default(), SearchOptions::default(), constraints)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cheapest = branches.iter().map(|branch| branch.total_cost).min().unwrap();
+        Ok(branches.into_iter().filter(|branch| branch.total_cost == cheapest).collect())
+    }
+
+    #[test]
+    fn constrained_plans() {
+        let items = items();
+        let best = best_branch(&items, Rules::default(), SearchOptions::default()).unwrap();
+
+        assert_eq!(cheapest(&items, &[]).unwrap()[0].total_cost, best.total_cost);
+
+        let last = cheapest(&items, &[Constraint::ApplyLast(4)]).unwrap();
+        assert!(last.iter().all(|branch| branch.steps.last().unwrap().sacrifice == Some(items[4])));
+        assert!(last[0].total_cost >= best.total_cost);
+
+        // Every book goes onto the sword on its own, so the sword is the target of every step.
+        let direct = cheapest(&items, &(1..5).map(Constraint::ApplyToTarget).collect::<Vec<_>>()).unwrap();
+        assert!(direct.iter().all(|branch| branch.steps.iter().all(|step| step.target.kind == ItemKind::Sword)));
+        assert!(direct.iter().all(|branch| branch.steps.iter().filter_map(|step| step.sacrifice).all(|sacrifice| items.contains(&sacrifice))));
+
+        let together = cheapest(&items, &[Constraint::MergeTogether(vec![1, 4]), Constraint::ApplyToTarget(2)]).unwrap();
+        assert!(together.iter().all(|branch| {
+            branch.steps.iter().any(|step| {
+                [step.target, step.sacrifice.unwrap()] == [items[1], items[4]] || [step.target, step.sacrifice.unwrap()] == [items[4], items[1]]
+            }) && branch.steps.iter().any(|step| step.target.kind == ItemKind::Sword && step.sacrifice == Some(items[2]))
+        }));
+    }
+
+    #[test]
+    fn unmet() {
+        let items = items();
+
+        assert_eq!(cheapest(&items, &[Constraint::ApplyLast(5)]), Err(CombinationError::NoSuchItem { position: 5, count: 5 }));
+        assert_eq!(cheapest(&items, &[Constraint::ApplyLast(1), Constraint::ApplyLast(2)]), Err(CombinationError::ConstraintsUnmet));
+        // The sword can only be applied to a book, which the anvil refuses.
+        assert!(matches!(cheapest(&items, &[Constraint::ApplyToTarget(0)]), Err(CombinationError::WrongItemKind { .. })));
+        assert_eq!(
+            cheapest(&items, &[Constraint::MergeTogether(vec![1, 2]), Constraint::MergeTogether(vec![2, 3])]),
+            Err(CombinationError::ConstraintsUnmet)
+        );
+    }
+
+    #[test]
+    fn equal_items() {
+        let items = [
+            item(ItemKind::Pickaxe, &[], 0),
+            book(EnchantmentKind::Efficiency, 4),
+            book(EnchantmentKind::Efficiency, 4),
+            book(EnchantmentKind::Unbreaking, 3),
+        ];
+
+        // Plans that swap the equal books keep to the constraints only one way round.
+        let branches = cheapest(&items, &[Constraint::ApplyToTarget(2), Constraint::MergeTogether(vec![1, 3])]).unwrap();
+
+        assert!(branches.iter().all(|branch| {
+            branch.steps.iter().any(|step| step.target.kind == ItemKind::Pickaxe && step.sacrifice == Some(items[2]))
+                && branch.steps.iter().any(|step| step.target.kind == ItemKind::Book && step.sacrifice.is_some())
+        }));
+    }
+
+    #[test]
+    fn best_matches_enumeration() {
+        let items = items();
+        let pickaxe = [
+            item(ItemKind::Pickaxe, &[], 0),
+            book(EnchantmentKind::Efficiency, 4),
+            book(EnchantmentKind::Efficiency, 4),
+            book(EnchantmentKind::Unbreaking, 3),
+        ];
+
+        let cases = [
+            (&items[..], vec![Constraint::ApplyLast(4)]),
+            (&items[..], (1..5).map(Constraint::ApplyToTarget).collect()),
+            (&items[..], vec![Constraint::MergeTogether(vec![1, 4]), Constraint::ApplyToTarget(2)]),
+            (&items[..], vec![Constraint::ApplyLast(5)]),
+            (&items[..], vec![Constraint::ApplyLast(1), Constraint::ApplyLast(2)]),
+            (&items[..], vec![Constraint::ApplyToTarget(0)]),
+            (&pickaxe[..], vec![Constraint::ApplyToTarget(2), Constraint::MergeTogether(vec![1, 3])]),
+        ];
+
+        for (items, constraints) in cases {
+            let best = constrained_best_branch(items, Rules::default(), SearchOptions::default(), &constraints);
+
+            assert_eq!(best, cheapest(items, &constraints).map(|branches| branches[0].clone()));
+        }
+    }
+}
@@ -1,2 +1,78 @@
-pub static C:[u8;0]=[];pub static C9:[u8;1]=[9];pub static C8:[u8;1]=[8];pub static C89:[u8;2]=[8,9];pub static C7:[u8;1]=[7];pub static C79:[u8;2]=[7,9];pub static C78:[u8;2]=[7,8];pub static C789:[u8;3]=[7,8,9];pub static C6:[u8;1]=[6];pub static C69:[u8;2]=[6,9];pub static C68:[u8;2]=[6,8];pub static C689:[u8;3]=[6,8,9];pub static C67:[u8;2]=[6,7];pub static C679:[u8;3]=[6,7,9];pub static C678:[u8;3]=[6,7,8];pub static C6789:[u8;4]=[6,7,8,9];pub static C5:[u8;1]=[5];pub static C59:[u8;2]=[5,9];pub static C58:[u8;2]=[5,8];pub static C589:[u8;3]=[5,8,9];pub static C57:[u8;2]=[5,7];pub static C579:[u8;3]=[5,7,9];pub static C578:[u8;3]=[5,7,8];pub static C5789:[u8;4]=[5,7,8,9];pub static C56:[u8;2]=[5,6];pub static C569:[u8;3]=[5,6,9];pub static C568:[u8;3]=[5,6,8];pub static C5689:[u8;4]=[5,6,8,9];pub static C567:[u8;3]=[5,6,7];pub static C5679:[u8;4]=[5,6,7,9];pub static C5678:[u8;4]=[5,6,7,8];pub static C56789:[u8;5]=[5,6,7,8,9];pub static C4:[u8;1]=[4];pub static C49:[u8;2]=[4,9];pub static C48:[u8;2]=[4,8];pub static C489:[u8;3]=[4,8,9];pub static C47:[u8;2]=[4,7];pub static C479:[u8;3]=[4,7,9];pub static C478:[u8;3]=[4,7,8];pub static C4789:[u8;4]=[4,7,8,9];pub static C46:[u8;2]=[4,6];pub static C469:[u8;3]=[4,6,9];pub static C468:[u8;3]=[4,6,8];pub static C4689:[u8;4]=[4,6,8,9];pub static C467:[u8;3]=[4,6,7];pub static C4679:[u8;4]=[4,6,7,9];pub static C4678:[u8;4]=[4,6,7,8];pub static C46789:[u8;5]=[4,6,7,8,9];pub static C45:[u8;2]=[4,5];pub static C459:[u8;3]=[4,5,9];pub static C458:[u8;3]=[4,5,8];pub static C4589:[u8;4]=[4,5,8,9];pub static C457:[u8;3]=[4,5,7];pub static C4579:[u8;4]=[4,5,7,9];pub static C4578:[u8;4]=[4,5,7,8];pub static C45789:[u8;5]=[4,5,7,8,9];pub static C456:[u8;3]=[4,5,6];pub static C4569:[u8;4]=[4,5,6,9];pub static C4568:[u8;4]=[4,5,6,8];pub static C45689:[u8;5]=[4,5,6,8,9];pub static C4567:[u8;4]=[4,5,6,7];pub static C45679:[u8;5]=[4,5,6,7,9];pub static C45678:[u8;5]=[4,5,6,7,8];pub static C456789:[u8;6]=[4,5,6,7,8,9];pub static C3:[u8;1]=[3];pub static C39:[u8;2]=[3,9];pub static C38:[u8;2]=[3,8];pub static C389:[u8;3]=[3,8,9];pub static C37:[u8;2]=[3,7];pub static C379:[u8;3]=[3,7,9];pub static C378:[u8;3]=[3,7,8];pub static C3789:[u8;4]=[3,7,8,9];pub static C36:[u8;2]=[3,6];pub static C369:[u8;3]=[3,6,9];pub static C368:[u8;3]=[3,6,8];pub static C3689:[u8;4]=[3,6,8,9];pub static C367:[u8;3]=[3,6,7];pub static C3679:[u8;4]=[3,6,7,9];pub static C3678:[u8;4]=[3,6,7,8];pub static C36789:[u8;5]=[3,6,7,8,9];pub static C35:[u8;2]=[3,5];pub static C359:[u8;3]=[3,5,9];pub static C358:[u8;3]=[3,5,8];pub static C3589:[u8;4]=[3,5,8,9];pub static C357:[u8;3]=[3,5,7];pub static C3579:[u8;4]=[3,5,7,9];pub static C3578:[u8;4]=[3,5,7,8];pub static C35789:[u8;5]=[3,5,7,8,9];pub static C356:[u8;3]=[3,5,6];pub static C3569:[u8;4]=[3,5,6,9];pub static C3568:[u8;4]=[3,5,6,8];pub static C35689:[u8;5]=[3,5,6,8,9];pub static C3567:[u8;4]=[3,5,6,7];pub static C35679:[u8;5]=[3,5,6,7,9];pub static C35678:[u8;5]=[3,5,6,7,8];pub static C356789:[u8;6]=[3,5,6,7,8,9];pub static C34:[u8;2]=[3,4];pub static C349:[u8;3]=[3,4,9];pub static C348:[u8;3]=[3,4,8];pub static C3489:[u8;4]=[3,4,8,9];pub static C347:[u8;3]=[3,4,7];pub static C3479:[u8;4]=[3,4,7,9];pub static C3478:[u8;4]=[3,4,7,8];pub static C34789:[u8;5]=[3,4,7,8,9];pub static C346:[u8;3]=[3,4,6];pub static C3469:[u8;4]=[3,4,6,9];pub static C3468:[u8;4]=[3,4,6,8];pub static C34689:[u8;5]=[3,4,6,8,9];pub static C3467:[u8;4]=[3,4,6,7];pub static C34679:[u8;5]=[3,4,6,7,9];pub static C34678:[u8;5]=[3,4,6,7,8];pub static C346789:[u8;6]=[3,4,6,7,8,9];pub static C345:[u8;3]=[3,4,5];pub static C3459:[u8;4]=[3,4,5,9];pub static C3458:[u8;4]=[3,4,5,8];pub static C34589:[u8;5]=[3,4,5,8,9];pub static C3457:[u8;4]=[3,4,5,7];pub static C34579:[u8;5]=[3,4,5,7,9];pub static C34578:[u8;5]=[3,4,5,7,8];pub static C345789:[u8;6]=[3,4,5,7,8,9];pub static C3456:[u8;4]=[3,4,5,6];pub static C34569:[u8;5]=[3,4,5,6,9];pub static C34568:[u8;5]=[3,4,5,6,8];pub static C345689:[u8;6]=[3,4,5,6,8,9];pub static C34567:[u8;5]=[3,4,5,6,7];pub static C345679:[u8;6]=[3,4,5,6,7,9];pub static C345678:[u8;6]=[3,4,5,6,7,8];pub static C3456789:[u8;7]=[3,4,5,6,7,8,9];pub static C2:[u8;1]=[2];pub static C29:[u8;2]=[2,9];pub static C28:[u8;2]=[2,8];pub static C289:[u8;3]=[2,8,9];pub static C27:[u8;2]=[2,7];pub static C279:[u8;3]=[2,7,9];pub static C278:[u8;3]=[2,7,8];pub static C2789:[u8;4]=[2,7,8,9];pub static C26:[u8;2]=[2,6];pub static C269:[u8;3]=[2,6,9];pub static C268:[u8;3]=[2,6,8];pub static C2689:[u8;4]=[2,6,8,9];pub static C267:[u8;3]=[2,6,7];pub static C2679:[u8;4]=[2,6,7,9];pub static C2678:[u8;4]=[2,6,7,8];pub static C26789:[u8;5]=[2,6,7,8,9];pub static C25:[u8;2]=[2,5];pub static C259:[u8;3]=[2,5,9];pub static C258:[u8;3]=[2,5,8];pub static C2589:[u8;4]=[2,5,8,9];pub static C257:[u8;3]=[2,5,7];pub static C2579:[u8;4]=[2,5,7,9];pub static C2578:[u8;4]=[2,5,7,8];pub static C25789:[u8;5]=[2,5,7,8,9];pub static C256:[u8;3]=[2,5,6];pub static C2569:[u8;4]=[2,5,6,9];pub static C2568:[u8;4]=[2,5,6,8];pub static C25689:[u8;5]=[2,5,6,8,9];pub static C2567:[u8;4]=[2,5,6,7];pub static C25679:[u8;5]=[2,5,6,7,9];pub static C25678:[u8;5]=[2,5,6,7,8];pub static C256789:[u8;6]=[2,5,6,7,8,9];pub static C24:[u8;2]=[2,4];pub static C249:[u8;3]=[2,4,9];pub static C248:[u8;3]=[2,4,8];pub static C2489:[u8;4]=[2,4,8,9];pub static C247:[u8;3]=[2,4,7];pub static C2479:[u8;4]=[2,4,7,9];pub static C2478:[u8;4]=[2,4,7,8];pub static C24789:[u8;5]=[2,4,7,8,9];pub static C246:[u8;3]=[2,4,6];pub static C2469:[u8;4]=[2,4,6,9];pub static C2468:[u8;4]=[2,4,6,8];pub static C24689:[u8;5]=[2,4,6,8,9];pub static C2467:[u8;4]=[2,4,6,7];pub static C24679:[u8;5]=[2,4,6,7,9];pub static C24678:[u8;5]=[2,4,6,7,8];pub static C246789:[u8;6]=[2,4,6,7,8,9];pub static C245:[u8;3]=[2,4,5];pub static C2459:[u8;4]=[2,4,5,9];pub static C2458:[u8;4]=[2,4,5,8];pub static C24589:[u8;5]=[2,4,5,8,9];pub static C2457:[u8;4]=[2,4,5,7];pub static C24579:[u8;5]=[2,4,5,7,9];pub static C24578:[u8;5]=[2,4,5,7,8];pub static C245789:[u8;6]=[2,4,5,7,8,9];pub static C2456:[u8;4]=[2,4,5,6];pub static C24569:[u8;5]=[2,4,5,6,9];pub static C24568:[u8;5]=[2,4,5,6,8];pub static C245689:[u8;6]=[2,4,5,6,8,9];pub static C24567:[u8;5]=[2,4,5,6,7];pub static C245679:[u8;6]=[2,4,5,6,7,9];pub static C245678:[u8;6]=[2,4,5,6,7,8];pub static C2456789:[u8;7]=[2,4,5,6,7,8,9];pub static C23:[u8;2]=[2,3];pub static C239:[u8;3]=[2,3,9];pub static C238:[u8;3]=[2,3,8];pub static C2389:[u8;4]=[2,3,8,9];pub static C237:[u8;3]=[2,3,7];pub static C2379:[u8;4]=[2,3,7,9];pub static C2378:[u8;4]=[2,3,7,8];pub static C23789:[u8;5]=[2,3,7,8,9];pub static C236:[u8;3]=[2,3,6];pub static C2369:[u8;4]=[2,3,6,9];pub static C2368:[u8;4]=[2,3,6,8];pub static C23689:[u8;5]=[2,3,6,8,9];pub static C2367:[u8;4]=[2,3,6,7];pub static C23679:[u8;5]=[2,3,6,7,9];pub static C23678:[u8;5]=[2,3,6,7,8];pub static C236789:[u8;6]=[2,3,6,7,8,9];pub static C235:[u8;3]=[2,3,5];pub static C2359:[u8;4]=[2,3,5,9];pub static C2358:[u8;4]=[2,3,5,8];pub static C23589:[u8;5]=[2,3,5,8,9];pub static C2357:[u8;4]=[2,3,5,7];pub static C23579:[u8;5]=[2,3,5,7,9];pub static C23578:[u8;5]=[2,3,5,7,8];pub static C235789:[u8;6]=[2,3,5,7,8,9];pub static C2356:[u8;4]=[2,3,5,6];pub static C23569:[u8;5]=[2,3,5,6,9];pub static C23568:[u8;5]=[2,3,5,6,8];pub static C235689:[u8;6]=[2,3,5,6,8,9];pub static C23567:[u8;5]=[2,3,5,6,7];pub static C235679:[u8;6]=[2,3,5,6,7,9];pub static C235678:[u8;6]=[2,3,5,6,7,8];pub static C2356789:[u8;7]=[2,3,5,6,7,8,9];pub static C234:[u8;3]=[2,3,4];pub static C2349:[u8;4]=[2,3,4,9];pub static C2348:[u8;4]=[2,3,4,8];pub static C23489:[u8;5]=[2,3,4,8,9];pub static C2347:[u8;4]=[2,3,4,7];pub static C23479:[u8;5]=[2,3,4,7,9];pub static C23478:[u8;5]=[2,3,4,7,8];pub static C234789:[u8;6]=[2,3,4,7,8,9];pub static C2346:[u8;4]=[2,3,4,6];pub static C23469:[u8;5]=[2,3,4,6,9];pub static C23468:[u8;5]=[2,3,4,6,8];pub static C234689:[u8;6]=[2,3,4,6,8,9];pub static C23467:[u8;5]=[2,3,4,6,7];pub static C234679:[u8;6]=[2,3,4,6,7,9];pub static C234678:[u8;6]=[2,3,4,6,7,8];pub static C2346789:[u8;7]=[2,3,4,6,7,8,9];pub static C2345:[u8;4]=[2,3,4,5];pub static C23459:[u8;5]=[2,3,4,5,9];pub static C23458:[u8;5]=[2,3,4,5,8];pub static C234589:[u8;6]=[2,3,4,5,8,9];pub static C23457:[u8;5]=[2,3,4,5,7];pub static C234579:[u8;6]=[2,3,4,5,7,9];pub static C234578:[u8;6]=[2,3,4,5,7,8];pub static C2345789:[u8;7]=[2,3,4,5,7,8,9];pub static C23456:[u8;5]=[2,3,4,5,6];pub static C234569:[u8;6]=[2,3,4,5,6,9];pub static C234568:[u8;6]=[2,3,4,5,6,8];pub static C2345689:[u8;7]=[2,3,4,5,6,8,9];pub static C234567:[u8;6]=[2,3,4,5,6,7];pub static C2345679:[u8;7]=[2,3,4,5,6,7,9];pub static C2345678:[u8;7]=[2,3,4,5,6,7,8];pub static C23456789:[u8;8]=[2,3,4,5,6,7,8,9];pub static C1:[u8;1]=[1];pub static C19:[u8;2]=[1,9];pub static C18:[u8;2]=[1,8];pub static C189:[u8;3]=[1,8,9];pub static C17:[u8;2]=[1,7];pub static C179:[u8;3]=[1,7,9];pub static C178:[u8;3]=[1,7,8];pub static C1789:[u8;4]=[1,7,8,9];pub static C16:[u8;2]=[1,6];pub static C169:[u8;3]=[1,6,9];pub static C168:[u8;3]=[1,6,8];pub static C1689:[u8;4]=[1,6,8,9];pub static C167:[u8;3]=[1,6,7];pub static C1679:[u8;4]=[1,6,7,9];pub static C1678:[u8;4]=[1,6,7,8];pub static C16789:[u8;5]=[1,6,7,8,9];pub static C15:[u8;2]=[1,5];pub static C159:[u8;3]=[1,5,9];pub static C158:[u8;3]=[1,5,8];pub static C1589:[u8;4]=[1,5,8,9];pub static C157:[u8;3]=[1,5,7];pub static C1579:[u8;4]=[1,5,7,9];pub static C1578:[u8;4]=[1,5,7,8];pub static C15789:[u8;5]=[1,5,7,8,9];pub static C156:[u8;3]=[1,5,6];pub static C1569:[u8;4]=[1,5,6,9];pub static C1568:[u8;4]=[1,5,6,8];pub static C15689:[u8;5]=[1,5,6,8,9];pub static C1567:[u8;4]=[1,5,6,7];pub static C15679:[u8;5]=[1,5,6,7,9];pub static C15678:[u8;5]=[1,5,6,7,8];pub static C156789:[u8;6]=[1,5,6,7,8,9];pub static C14:[u8;2]=[1,4];pub static C149:[u8;3]=[1,4,9];pub static C148:[u8;3]=[1,4,8];pub static C1489:[u8;4]=[1,4,8,9];pub static C147:[u8;3]=[1,4,7];pub static C1479:[u8;4]=[1,4,7,9];pub static C1478:[u8;4]=[1,4,7,8];pub static C14789:[u8;5]=[1,4,7,8,9];pub static C146:[u8;3]=[1,4,6];pub static C1469:[u8;4]=[1,4,6,9];pub static C1468:[u8;4]=[1,4,6,8];pub static C14689:[u8;5]=[1,4,6,8,9];pub static C1467:[u8;4]=[1,4,6,7];pub static C14679:[u8;5]=[1,4,6,7,9];pub static C14678:[u8;5]=[1,4,6,7,8];pub static C146789:[u8;6]=[1,4,6,7,8,9];pub static C145:[u8;3]=[1,4,5];pub static C1459:[u8;4]=[1,4,5,9];pub static C1458:[u8;4]=[1,4,5,8];pub static C14589:[u8;5]=[1,4,5,8,9];pub static C1457:[u8;4]=[1,4,5,7];pub static C14579:[u8;5]=[1,4,5,7,9];pub static C14578:[u8;5]=[1,4,5,7,8];pub static C145789:[u8;6]=[1,4,5,7,8,9];pub static C1456:[u8;4]=[1,4,5,6];pub static C14569:[u8;5]=[1,4,5,6,9];pub static C14568:[u8;5]=[1,4,5,6,8];pub static C145689:[u8;6]=[1,4,5,6,8,9];pub static C14567:[u8;5]=[1,4,5,6,7];pub static C145679:[u8;6]=[1,4,5,6,7,9];pub static C145678:[u8;6]=[1,4,5,6,7,8];pub static C1456789:[u8;7]=[1,4,5,6,7,8,9];pub static C13:[u8;2]=[1,3];pub static C139:[u8;3]=[1,3,9];pub static C138:[u8;3]=[1,3,8];pub static C1389:[u8;4]=[1,3,8,9];pub static C137:[u8;3]=[1,3,7];pub static C1379:[u8;4]=[1,3,7,9];pub static C1378:[u8;4]=[1,3,7,8];pub static C13789:[u8;5]=[1,3,7,8,9];pub static C136:[u8;3]=[1,3,6];pub static C1369:[u8;4]=[1,3,6,9];pub static C1368:[u8;4]=[1,3,6,8];pub static C13689:[u8;5]=[1,3,6,8,9];pub static C1367:[u8;4]=[1,3,6,7];pub static C13679:[u8;5]=[1,3,6,7,9];pub static C13678:[u8;5]=[1,3,6,7,8];pub static C136789:[u8;6]=[1,3,6,7,8,9];pub static C135:[u8;3]=[1,3,5];pub static C1359:[u8;4]=[1,3,5,9];pub static C1358:[u8;4]=[1,3,5,8];pub static C13589:[u8;5]=[1,3,5,8,9];pub static C1357:[u8;4]=[1,3,5,7];pub static C13579:[u8;5]=[1,3,5,7,9];pub static C13578:[u8;5]=[1,3,5,7,8];pub static C135789:[u8;6]=[1,3,5,7,8,9];pub static C1356:[u8;4]=[1,3,5,6];pub static C13569:[u8;5]=[1,3,5,6,9];pub static C13568:[u8;5]=[1,3,5,6,8];pub static C135689:[u8;6]=[1,3,5,6,8,9];pub static C13567:[u8;5]=[1,3,5,6,7];pub static C135679:[u8;6]=[1,3,5,6,7,9];pub static C135678:[u8;6]=[1,3,5,6,7,8];pub static C1356789:[u8;7]=[1,3,5,6,7,8,9];pub static C134:[u8;3]=[1,3,4];pub static C1349:[u8;4]=[1,3,4,9];pub static C1348:[u8;4]=[1,3,4,8];pub static C13489:[u8;5]=[1,3,4,8,9];pub static C1347:[u8;4]=[1,3,4,7];pub static C13479:[u8;5]=[1,3,4,7,9];pub static C13478:[u8;5]=[1,3,4,7,8];pub static C134789:[u8;6]=[1,3,4,7,8,9];pub static C1346:[u8;4]=[1,3,4,6];pub static C13469:[u8;5]=[1,3,4,6,9];pub static C13468:[u8;5]=[1,3,4,6,8];pub static C134689:[u8;6]=[1,3,4,6,8,9];pub static C13467:[u8;5]=[1,3,4,6,7];pub static C134679:[u8;6]=[1,3,4,6,7,9];pub static C134678:[u8;6]=[1,3,4,6,7,8];pub static C1346789:[u8;7]=[1,3,4,6,7,8,9];pub static C1345:[u8;4]=[1,3,4,5];pub static C13459:[u8;5]=[1,3,4,5,9];pub static C13458:[u8;5]=[1,3,4,5,8];pub static C134589:[u8;6]=[1,3,4,5,8,9];pub static C13457:[u8;5]=[1,3,4,5,7];pub static C134579:[u8;6]=[1,3,4,5,7,9];pub static C134578:[u8;6]=[1,3,4,5,7,8];pub static C1345789:[u8;7]=[1,3,4,5,7,8,9];pub static C13456:[u8;5]=[1,3,4,5,6];pub static C134569:[u8;6]=[1,3,4,5,6,9];pub static C134568:[u8;6]=[1,3,4,5,6,8];pub static C1345689:[u8;7]=[1,3,4,5,6,8,9];pub static C134567:[u8;6]=[1,3,4,5,6,7];pub static C1345679:[u8;7]=[1,3,4,5,6,7,9];pub static C1345678:[u8;7]=[1,3,4,5,6,7,8];pub static C13456789:[u8;8]=[1,3,4,5,6,7,8,9];pub static C12:[u8;2]=[1,2];pub static C129:[u8;3]=[1,2,9];pub static C128:[u8;3]=[1,2,8];pub static C1289:[u8;4]=[1,2,8,9];pub static C127:[u8;3]=[1,2,7];pub static C1279:[u8;4]=[1,2,7,9];pub static C1278:[u8;4]=[1,2,7,8];pub static C12789:[u8;5]=[1,2,7,8,9];pub static C126:[u8;3]=[1,2,6];pub static C1269:[u8;4]=[1,2,6,9];pub static C1268:[u8;4]=[1,2,6,8];pub static C12689:[u8;5]=[1,2,6,8,9];pub static C1267:[u8;4]=[1,2,6,7];pub static C12679:[u8;5]=[1,2,6,7,9];pub static C12678:[u8;5]=[1,2,6,7,8];pub static C126789:[u8;6]=[1,2,6,7,8,9];pub static C125:[u8;3]=[1,2,5];pub static C1259:[u8;4]=[1,2,5,9];pub static C1258:[u8;4]=[1,2,5,8];pub static C12589:[u8;5]=[1,2,5,8,9];pub static C1257:[u8;4]=[1,2,5,7];pub static C12579:[u8;5]=[1,2,5,7,9];pub static C12578:[u8;5]=[1,2,5,7,8];pub static C125789:[u8;6]=[1,2,5,7,8,9];pub static C1256:[u8;4]=[1,2,5,6];pub static C12569:[u8;5]=[1,2,5,6,9];pub static C12568:[u8;5]=[1,2,5,6,8];pub static C125689:[u8;6]=[1,2,5,6,8,9];pub static C12567:[u8;5]=[1,2,5,6,7];pub static C125679:[u8;6]=[1,2,5,6,7,9];pub static C125678:[u8;6]=[1,2,5,6,7,8];pub static C1256789:[u8;7]=[1,2,5,6,7,8,9];pub static C124:[u8;3]=[1,2,4];pub static C1249:[u8;4]=[1,2,4,9];pub static C1248:[u8;4]=[1,2,4,8];pub static C12489:[u8;5]=[1,2,4,8,9];pub static C1247:[u8;4]=[1,2,4,7];pub static C12479:[u8;5]=[1,2,4,7,9];pub static C12478:[u8;5]=[1,2,4,7,8];pub static C124789:[u8;6]=[1,2,4,7,8,9];pub static C1246:[u8;4]=[1,2,4,6];pub static C12469:[u8;5]=[1,2,4,6,9];pub static C12468:[u8;5]=[1,2,4,6,8];pub static C124689:[u8;6]=[1,2,4,6,8,9];pub static C12467:[u8;5]=[1,2,4,6,7];pub static C124679:[u8;6]=[1,2,4,6,7,9];pub static C124678:[u8;6]=[1,2,4,6,7,8];pub static C1246789:[u8;7]=[1,2,4,6,7,8,9];pub static C1245:[u8;4]=[1,2,4,5];pub static C12459:[u8;5]=[1,2,4,5,9];pub static C12458:[u8;5]=[1,2,4,5,8];pub static C124589:[u8;6]=[1,2,4,5,8,9];pub static C12457:[u8;5]=[1,2,4,5,7];pub static C124579:[u8;6]=[1,2,4,5,7,9];pub static C124578:[u8;6]=[1,2,4,5,7,8];pub static C1245789:[u8;7]=[1,2,4,5,7,8,9];pub static C12456:[u8;5]=[1,2,4,5,6];pub static C124569:[u8;6]=[1,2,4,5,6,9];pub static C124568:[u8;6]=[1,2,4,5,6,8];pub static C1245689:[u8;7]=[1,2,4,5,6,8,9];pub static C124567:[u8;6]=[1,2,4,5,6,7];pub static C1245679:[u8;7]=[1,2,4,5,6,7,9];pub static C1245678:[u8;7]=[1,2,4,5,6,7,8];pub static C12456789:[u8;8]=[1,2,4,5,6,7,8,9];pub static C123:[u8;3]=[1,2,3];pub static C1239:[u8;4]=[1,2,3,9];pub static C1238:[u8;4]=[1,2,3,8];pub static C12389:[u8;5]=[1,2,3,8,9];pub static C1237:[u8;4]=[1,2,3,7];pub static C12379:[u8;5]=[1,2,3,7,9];pub static C12378:[u8;5]=[1,2,3,7,8];pub static C123789:[u8;6]=[1,2,3,7,8,9];pub static C1236:[u8;4]=[1,2,3,6];pub static C12369:[u8;5]=[1,2,3,6,9];pub static C12368:[u8;5]=[1,2,3,6,8];pub static C123689:[u8;6]=[1,2,3,6,8,9];pub static C12367:[u8;5]=[1,2,3,6,7];pub static C123679:[u8;6]=[1,2,3,6,7,9];pub static C123678:[u8;6]=[1,2,3,6,7,8];pub static C1236789:[u8;7]=[1,2,3,6,7,8,9];pub static C1235:[u8;4]=[1,2,3,5];pub static C12359:[u8;5]=[1,2,3,5,9];pub static C12358:[u8;5]=[1,2,3,5,8];pub static C123589:[u8;6]=[1,2,3,5,8,9];pub static C12357:[u8;5]=[1,2,3,5,7];pub static C123579:[u8;6]=[1,2,3,5,7,9];pub static C123578:[u8;6]=[1,2,3,5,7,8];pub static C1235789:[u8;7]=[1,2,3,5,7,8,9];pub static C12356:[u8;5]=[1,2,3,5,6];pub static C123569:[u8;6]=[1,2,3,5,6,9];pub static C123568:[u8;6]=[1,2,3,5,6,8];pub static C1235689:[u8;7]=[1,2,3,5,6,8,9];pub static C123567:[u8;6]=[1,2,3,5,6,7];pub static C1235679:[u8;7]=[1,2,3,5,6,7,9];pub static C1235678:[u8;7]=[1,2,3,5,6,7,8];pub static C12356789:[u8;8]=[1,2,3,5,6,7,8,9];pub static C1234:[u8;4]=[1,2,3,4];pub static C12349:[u8;5]=[1,2,3,4,9];pub static C12348:[u8;5]=[1,2,3,4,8];pub static C123489:[u8;6]=[1,2,3,4,8,9];pub static C12347:[u8;5]=[1,2,3,4,7];pub static C123479:[u8;6]=[1,2,3,4,7,9];pub static C123478:[u8;6]=[1,2,3,4,7,8];pub static C1234789:[u8;7]=[1,2,3,4,7,8,9];pub static C12346:[u8;5]=[1,2,3,4,6];pub static C123469:[u8;6]=[1,2,3,4,6,9];pub static C123468:[u8;6]=[1,2,3,4,6,8];pub static C1234689:[u8;7]=[1,2,3,4,6,8,9];pub static C123467:[u8;6]=[1,2,3,4,6,7];pub static C1234679:[u8;7]=[1,2,3,4,6,7,9];pub static C1234678:[u8;7]=[1,2,3,4,6,7,8];pub static C12346789:[u8;8]=[1,2,3,4,6,7,8,9];pub static C12345:[u8;5]=[1,2,3,4,5];pub static C123459:[u8;6]=[1,2,3,4,5,9];pub static C123458:[u8;6]=[1,2,3,4,5,8];pub static C1234589:[u8;7]=[1,2,3,4,5,8,9];pub static C123457:[u8;6]=[1,2,3,4,5,7];pub static C1234579:[u8;7]=[1,2,3,4,5,7,9];pub static C1234578:[u8;7]=[1,2,3,4,5,7,8];pub static C12345789:[u8;8]=[1,2,3,4,5,7,8,9];pub static C123456:[u8;6]=[1,2,3,4,5,6];pub static C1234569:[u8;7]=[1,2,3,4,5,6,9];pub static C1234568:[u8;7]=[1,2,3,4,5,6,8];pub static C12345689:[u8;8]=[1,2,3,4,5,6,8,9];pub static C1234567:[u8;7]=[1,2,3,4,5,6,7];pub static C12345679:[u8;8]=[1,2,3,4,5,6,7,9];pub static C12345678:[u8;8]=[1,2,3,4,5,6,7,8];pub static C123456789:[u8;9]=[1,2,3,4,5,6,7,8,9];pub static C0:[u8;1]=[0];pub static C09:[u8;2]=[0,9];pub static C08:[u8;2]=[0,8];pub static C089:[u8;3]=[0,8,9];pub static C07:[u8;2]=[0,7];pub static C079:[u8;3]=[0,7,9];pub static C078:[u8;3]=[0,7,8];pub static C0789:[u8;4]=[0,7,8,9];pub static C06:[u8;2]=[0,6];pub static C069:[u8;3]=[0,6,9];pub static C068:[u8;3]=[0,6,8];pub static C0689:[u8;4]=[0,6,8,9];pub static C067:[u8;3]=[0,6,7];pub static C0679:[u8;4]=[0,6,7,9];pub static C0678:[u8;4]=[0,6,7,8];pub static C06789:[u8;5]=[0,6,7,8,9];pub static C05:[u8;2]=[0,5];pub static C059:[u8;3]=[0,5,9];pub static C058:[u8;3]=[0,5,8];pub static C0589:[u8;4]=[0,5,8,9];pub static C057:[u8;3]=[0,5,7];pub static C0579:[u8;4]=[0,5,7,9];pub static C0578:[u8;4]=[0,5,7,8];pub static C05789:[u8;5]=[0,5,7,8,9];pub static C056:[u8;3]=[0,5,6];pub static C0569:[u8;4]=[0,5,6,9];pub static C0568:[u8;4]=[0,5,6,8];pub static C05689:[u8;5]=[0,5,6,8,9];pub static C0567:[u8;4]=[0,5,6,7];pub static C05679:[u8;5]=[0,5,6,7,9];pub static C05678:[u8;5]=[0,5,6,7,8];pub static C056789:[u8;6]=[0,5,6,7,8,9];pub static C04:[u8;2]=[0,4];pub static C049:[u8;3]=[0,4,9];pub static C048:[u8;3]=[0,4,8];pub static C0489:[u8;4]=[0,4,8,9];pub static C047:[u8;3]=[0,4,7];pub static C0479:[u8;4]=[0,4,7,9];pub static C0478:[u8;4]=[0,4,7,8];pub static C04789:[u8;5]=[0,4,7,8,9];pub static C046:[u8;3]=[0,4,6];pub static C0469:[u8;4]=[0,4,6,9];pub static C0468:[u8;4]=[0,4,6,8];pub static C04689:[u8;5]=[0,4,6,8,9];pub static C0467:[u8;4]=[0,4,6,7];pub static C04679:[u8;5]=[0,4,6,7,9];pub static C04678:[u8;5]=[0,4,6,7,8];pub static C046789:[u8;6]=[0,4,6,7,8,9];pub static C045:[u8;3]=[0,4,5];pub static C0459:[u8;4]=[0,4,5,9];pub static C0458:[u8;4]=[0,4,5,8];pub static C04589:[u8;5]=[0,4,5,8,9];pub static C0457:[u8;4]=[0,4,5,7];pub static C04579:[u8;5]=[0,4,5,7,9];pub static C04578:[u8;5]=[0,4,5,7,8];pub static C045789:[u8;6]=[0,4,5,7,8,9];pub static C0456:[u8;4]=[0,4,5,6];pub static C04569:[u8;5]=[0,4,5,6,9];pub static C04568:[u8;5]=[0,4,5,6,8];pub static C045689:[u8;6]=[0,4,5,6,8,9];pub static C04567:[u8;5]=[0,4,5,6,7];pub static C045679:[u8;6]=[0,4,5,6,7,9];pub static C045678:[u8;6]=[0,4,5,6,7,8];pub static C0456789:[u8;7]=[0,4,5,6,7,8,9];pub static C03:[u8;2]=[0,3];pub static C039:[u8;3]=[0,3,9];pub static C038:[u8;3]=[0,3,8];pub static C0389:[u8;4]=[0,3,8,9];pub static C037:[u8;3]=[0,3,7];pub static C0379:[u8;4]=[0,3,7,9];pub static C0378:[u8;4]=[0,3,7,8];pub static C03789:[u8;5]=[0,3,7,8,9];pub static C036:[u8;3]=[0,3,6];pub static C0369:[u8;4]=[0,3,6,9];pub static C0368:[u8;4]=[0,3,6,8];pub static C03689:[u8;5]=[0,3,6,8,9];pub static C0367:[u8;4]=[0,3,6,7];pub static C03679:[u8;5]=[0,3,6,7,9];pub static C03678:[u8;5]=[0,3,6,7,8];pub static C036789:[u8;6]=[0,3,6,7,8,9];pub static C035:[u8;3]=[0,3,5];pub static C0359:[u8;4]=[0,3,5,9];pub static C0358:[u8;4]=[0,3,5,8];pub static C03589:[u8;5]=[0,3,5,8,9];pub static C0357:[u8;4]=[0,3,5,7];pub static C03579:[u8;5]=[0,3,5,7,9];pub static C03578:[u8;5]=[0,3,5,7,8];pub static C035789:[u8;6]=[0,3,5,7,8,9];pub static C0356:[u8;4]=[0,3,5,6];pub static C03569:[u8;5]=[0,3,5,6,9];pub static C03568:[u8;5]=[0,3,5,6,8];pub static C035689:[u8;6]=[0,3,5,6,8,9];pub static C03567:[u8;5]=[0,3,5,6,7];pub static C035679:[u8;6]=[0,3,5,6,7,9];pub static C035678:[u8;6]=[0,3,5,6,7,8];pub static C0356789:[u8;7]=[0,3,5,6,7,8,9];pub static C034:[u8;3]=[0,3,4];pub static C0349:[u8;4]=[0,3,4,9];pub static C0348:[u8;4]=[0,3,4,8];pub static C03489:[u8;5]=[0,3,4,8,9];pub static C0347:[u8;4]=[0,3,4,7];pub static C03479:[u8;5]=[0,3,4,7,9];pub static C03478:[u8;5]=[0,3,4,7,8];pub static C034789:[u8;6]=[0,3,4,7,8,9];pub static C0346:[u8;4]=[0,3,4,6];pub static C03469:[u8;5]=[0,3,4,6,9];pub static C03468:[u8;5]=[0,3,4,6,8];pub static C034689:[u8;6]=[0,3,4,6,8,9];pub static C03467:[u8;5]=[0,3,4,6,7];pub static C034679:[u8;6]=[0,3,4,6,7,9];pub static C034678:[u8;6]=[0,3,4,6,7,8];pub static C0346789:[u8;7]=[0,3,4,6,7,8,9];pub static C0345:[u8;4]=[0,3,4,5];pub static C03459:[u8;5]=[0,3,4,5,9];pub static C03458:[u8;5]=[0,3,4,5,8];pub static C034589:[u8;6]=[0,3,4,5,8,9];pub static C03457:[u8;5]=[0,3,4,5,7];pub static C034579:[u8;6]=[0,3,4,5,7,9];pub static C034578:[u8;6]=[0,3,4,5,7,8];pub static C0345789:[u8;7]=[0,3,4,5,7,8,9];pub static C03456:[u8;5]=[0,3,4,5,6];pub static C034569:[u8;6]=[0,3,4,5,6,9];pub static C034568:[u8;6]=[0,3,4,5,6,8];pub static C0345689:[u8;7]=[0,3,4,5,6,8,9];pub static C034567:[u8;6]=[0,3,4,5,6,7];pub static C0345679:[u8;7]=[0,3,4,5,6,7,9];pub static C0345678:[u8;7]=[0,3,4,5,6,7,8];pub static C03456789:[u8;8]=[0,3,4,5,6,7,8,9];pub static C02:[u8;2]=[0,2];pub static C029:[u8;3]=[0,2,9];pub static C028:[u8;3]=[0,2,8];pub static C0289:[u8;4]=[0,2,8,9];pub static C027:[u8;3]=[0,2,7];pub static C0279:[u8;4]=[0,2,7,9];pub static C0278:[u8;4]=[0,2,7,8];pub static C02789:[u8;5]=[0,2,7,8,9];pub static C026:[u8;3]=[0,2,6];pub static C0269:[u8;4]=[0,2,6,9];pub static C0268:[u8;4]=[0,2,6,8];pub static C02689:[u8;5]=[0,2,6,8,9];pub static C0267:[u8;4]=[0,2,6,7];pub static C02679:[u8;5]=[0,2,6,7,9];pub static C02678:[u8;5]=[0,2,6,7,8];pub static C026789:[u8;6]=[0,2,6,7,8,9];pub static C025:[u8;3]=[0,2,5];pub static C0259:[u8;4]=[0,2,5,9];pub static C0258:[u8;4]=[0,2,5,8];pub static C02589:[u8;5]=[0,2,5,8,9];pub static C0257:[u8;4]=[0,2,5,7];pub static C02579:[u8;5]=[0,2,5,7,9];pub static C02578:[u8;5]=[0,2,5,7,8];pub static C025789:[u8;6]=[0,2,5,7,8,9];pub static C0256:[u8;4]=[0,2,5,6];pub static C02569:[u8;5]=[0,2,5,6,9];pub static C02568:[u8;5]=[0,2,5,6,8];pub static C025689:[u8;6]=[0,2,5,6,8,9];pub static C02567:[u8;5]=[0,2,5,6,7];pub static C025679:[u8;6]=[0,2,5,6,7,9];pub static C025678:[u8;6]=[0,2,5,6,7,8];pub static C0256789:[u8;7]=[0,2,5,6,7,8,9];pub static C024:[u8;3]=[0,2,4];pub static C0249:[u8;4]=[0,2,4,9];pub static C0248:[u8;4]=[0,2,4,8];pub static C02489:[u8;5]=[0,2,4,8,9];pub static C0247:[u8;4]=[0,2,4,7];pub static C02479:[u8;5]=[0,2,4,7,9];pub static C02478:[u8;5]=[0,2,4,7,8];pub static C024789:[u8;6]=[0,2,4,7,8,9];pub static C0246:[u8;4]=[0,2,4,6];pub static C02469:[u8;5]=[0,2,4,6,9];pub static C02468:[u8;5]=[0,2,4,6,8];pub static C024689:[u8;6]=[0,2,4,6,8,9];pub static C02467:[u8;5]=[0,2,4,6,7];pub static C024679:[u8;6]=[0,2,4,6,7,9];pub static C024678:[u8;6]=[0,2,4,6,7,8];pub static C0246789:[u8;7]=[0,2,4,6,7,8,9];pub static C0245:[u8;4]=[0,2,4,5];pub static C02459:[u8;5]=[0,2,4,5,9];pub static C02458:[u8;5]=[0,2,4,5,8];pub static C024589:[u8;6]=[0,2,4,5,8,9];pub static C02457:[u8;5]=[0,2,4,5,7];pub static C024579:[u8;6]=[0,2,4,5,7,9];pub static C024578:[u8;6]=[0,2,4,5,7,8];pub static C0245789:[u8;7]=[0,2,4,5,7,8,9];pub static C02456:[u8;5]=[0,2,4,5,6];pub static C024569:[u8;6]=[0,2,4,5,6,9];pub static C024568:[u8;6]=[0,2,4,5,6,8];pub static C0245689:[u8;7]=[0,2,4,5,6,8,9];pub static C024567:[u8;6]=[0,2,4,5,6,7];pub static C0245679:[u8;7]=[0,2,4,5,6,7,9];pub static C0245678:[u8;7]=[0,2,4,5,6,7,8];pub static C02456789:[u8;8]=[0,2,4,5,6,7,8,9];pub static C023:[u8;3]=[0,2,3];pub static C0239:[u8;4]=[0,2,3,9];pub static C0238:[u8;4]=[0,2,3,8];pub static C02389:[u8;5]=[0,2,3,8,9];pub static C0237:[u8;4]=[0,2,3,7];pub static C02379:[u8;5]=[0,2,3,7,9];pub static C02378:[u8;5]=[0,2,3,7,8];pub static C023789:[u8;6]=[0,2,3,7,8,9];pub static C0236:[u8;4]=[0,2,3,6];pub static C02369:[u8;5]=[0,2,3,6,9];pub static C02368:[u8;5]=[0,2,3,6,8];pub static C023689:[u8;6]=[0,2,3,6,8,9];pub static C02367:[u8;5]=[0,2,3,6,7];pub static C023679:[u8;6]=[0,2,3,6,7,9];pub static C023678:[u8;6]=[0,2,3,6,7,8];pub static C0236789:[u8;7]=[0,2,3,6,7,8,9];pub static C0235:[u8;4]=[0,2,3,5];pub static C02359:[u8;5]=[0,2,3,5,9];pub static C02358:[u8;5]=[0,2,3,5,8];pub static C023589:[u8;6]=[0,2,3,5,8,9];pub static C02357:[u8;5]=[0,2,3,5,7];pub static C023579:[u8;6]=[0,2,3,5,7,9];pub static C023578:[u8;6]=[0,2,3,5,7,8];pub static C0235789:[u8;7]=[0,2,3,5,7,8,9];pub static C02356:[u8;5]=[0,2,3,5,6];pub static C023569:[u8;6]=[0,2,3,5,6,9];pub static C023568:[u8;6]=[0,2,3,5,6,8];pub static C0235689:[u8;7]=[0,2,3,5,6,8,9];pub static C023567:[u8;6]=[0,2,3,5,6,7];pub static C0235679:[u8;7]=[0,2,3,5,6,7,9];pub static C0235678:[u8;7]=[0,2,3,5,6,7,8];pub static C02356789:[u8;8]=[0,2,3,5,6,7,8,9];pub static C0234:[u8;4]=[0,2,3,4];pub static C02349:[u8;5]=[0,2,3,4,9];pub static C02348:[u8;5]=[0,2,3,4,8];pub static C023489:[u8;6]=[0,2,3,4,8,9];pub static C02347:[u8;5]=[0,2,3,4,7];pub static C023479:[u8;6]=[0,2,3,4,7,9];pub static C023478:[u8;6]=[0,2,3,4,7,8];pub static C0234789:[u8;7]=[0,2,3,4,7,8,9];pub static C02346:[u8;5]=[0,2,3,4,6];pub static C023469:[u8;6]=[0,2,3,4,6,9];pub static C023468:[u8;6]=[0,2,3,4,6,8];pub static C0234689:[u8;7]=[0,2,3,4,6,8,9];pub static C023467:[u8;6]=[0,2,3,4,6,7];pub static C0234679:[u8;7]=[0,2,3,4,6,7,9];pub static C0234678:[u8;7]=[0,2,3,4,6,7,8];pub static C02346789:[u8;8]=[0,2,3,4,6,7,8,9];pub static C02345:[u8;5]=[0,2,3,4,5];pub static C023459:[u8;6]=[0,2,3,4,5,9];pub static C023458:[u8;6]=[0,2,3,4,5,8];pub static C0234589:[u8;7]=[0,2,3,4,5,8,9];pub static C023457:[u8;6]=[0,2,3,4,5,7];pub static C0234579:[u8;7]=[0,2,3,4,5,7,9];pub static C0234578:[u8;7]=[0,2,3,4,5,7,8];pub static C02345789:[u8;8]=[0,2,3,4,5,7,8,9];pub static C023456:[u8;6]=[0,2,3,4,5,6];pub static C0234569:[u8;7]=[0,2,3,4,5,6,9];pub static C0234568:[u8;7]=[0,2,3,4,5,6,8];pub static C02345689:[u8;8]=[0,2,3,4,5,6,8,9];pub static C0234567:[u8;7]=[0,2,3,4,5,6,7];pub static C02345679:[u8;8]=[0,2,3,4,5,6,7,9];pub static C02345678:[u8;8]=[0,2,3,4,5,6,7,8];pub static C023456789:[u8;9]=[0,2,3,4,5,6,7,8,9];pub static C01:[u8;2]=[0,1];pub static C019:[u8;3]=[0,1,9];pub static C018:[u8;3]=[0,1,8];pub static C0189:[u8;4]=[0,1,8,9];pub static C017:[u8;3]=[0,1,7];pub static C0179:[u8;4]=[0,1,7,9];pub static C0178:[u8;4]=[0,1,7,8];pub static C01789:[u8;5]=[0,1,7,8,9];pub static C016:[u8;3]=[0,1,6];pub static C0169:[u8;4]=[0,1,6,9];pub static C0168:[u8;4]=[0,1,6,8];pub static C01689:[u8;5]=[0,1,6,8,9];pub static C0167:[u8;4]=[0,1,6,7];pub static C01679:[u8;5]=[0,1,6,7,9];pub static C01678:[u8;5]=[0,1,6,7,8];pub static C016789:[u8;6]=[0,1,6,7,8,9];pub static C015:[u8;3]=[0,1,5];pub static C0159:[u8;4]=[0,1,5,9];pub static C0158:[u8;4]=[0,1,5,8];pub static C01589:[u8;5]=[0,1,5,8,9];pub static C0157:[u8;4]=[0,1,5,7];pub static C01579:[u8;5]=[0,1,5,7,9];pub static C01578:[u8;5]=[0,1,5,7,8];pub static C015789:[u8;6]=[0,1,5,7,8,9];pub static C0156:[u8;4]=[0,1,5,6];pub static C01569:[u8;5]=[0,1,5,6,9];pub static C01568:[u8;5]=[0,1,5,6,8];pub static C015689:[u8;6]=[0,1,5,6,8,9];pub static C01567:[u8;5]=[0,1,5,6,7];pub static C015679:[u8;6]=[0,1,5,6,7,9];pub static C015678:[u8;6]=[0,1,5,6,7,8];pub static C0156789:[u8;7]=[0,1,5,6,7,8,9];pub static C014:[u8;3]=[0,1,4];pub static C0149:[u8;4]=[0,1,4,9];pub static C0148:[u8;4]=[0,1,4,8];pub static C01489:[u8;5]=[0,1,4,8,9];pub static C0147:[u8;4]=[0,1,4,7];pub static C01479:[u8;5]=[0,1,4,7,9];pub static C01478:[u8;5]=[0,1,4,7,8];pub static C014789:[u8;6]=[0,1,4,7,8,9];pub static C0146:[u8;4]=[0,1,4,6];pub static C01469:[u8;5]=[0,1,4,6,9];pub static C01468:[u8;5]=[0,1,4,6,8];pub static C014689:[u8;6]=[0,1,4,6,8,9];pub static C01467:[u8;5]=[0,1,4,6,7];pub static C014679:[u8;6]=[0,1,4,6,7,9];pub static C014678:[u8;6]=[0,1,4,6,7,8];pub static C0146789:[u8;7]=[0,1,4,6,7,8,9];pub static C0145:[u8;4]=[0,1,4,5];pub static C01459:[u8;5]=[0,1,4,5,9];pub static C01458:[u8;5]=[0,1,4,5,8];pub static C014589:[u8;6]=[0,1,4,5,8,9];pub static C01457:[u8;5]=[0,1,4,5,7];pub static C014579:[u8;6]=[0,1,4,5,7,9];pub static C014578:[u8;6]=[0,1,4,5,7,8];pub static C0145789:[u8;7]=[0,1,4,5,7,8,9];pub static C01456:[u8;5]=[0,1,4,5,6];pub static C014569:[u8;6]=[0,1,4,5,6,9];pub static C014568:[u8;6]=[0,1,4,5,6,8];pub static C0145689:[u8;7]=[0,1,4,5,6,8,9];pub static C014567:[u8;6]=[0,1,4,5,6,7];pub static C0145679:[u8;7]=[0,1,4,5,6,7,9];pub static C0145678:[u8;7]=[0,1,4,5,6,7,8];pub static C01456789:[u8;8]=[0,1,4,5,6,7,8,9];pub static C013:[u8;3]=[0,1,3];pub static C0139:[u8;4]=[0,1,3,9];pub static C0138:[u8;4]=[0,1,3,8];pub static C01389:[u8;5]=[0,1,3,8,9];pub static C0137:[u8;4]=[0,1,3,7];pub static C01379:[u8;5]=[0,1,3,7,9];pub static C01378:[u8;5]=[0,1,3,7,8];pub static C013789:[u8;6]=[0,1,3,7,8,9];pub static C0136:[u8;4]=[0,1,3,6];pub static C01369:[u8;5]=[0,1,3,6,9];pub static C01368:[u8;5]=[0,1,3,6,8];pub static C013689:[u8;6]=[0,1,3,6,8,9];pub static C01367:[u8;5]=[0,1,3,6,7];pub static C013679:[u8;6]=[0,1,3,6,7,9];pub static C013678:[u8;6]=[0,1,3,6,7,8];pub static C0136789:[u8;7]=[0,1,3,6,7,8,9];pub static C0135:[u8;4]=[0,1,3,5];pub static C01359:[u8;5]=[0,1,3,5,9];pub static C01358:[u8;5]=[0,1,3,5,8];pub static C013589:[u8;6]=[0,1,3,5,8,9];pub static C01357:[u8;5]=[0,1,3,5,7];pub static C013579:[u8;6]=[0,1,3,5,7,9];pub static C013578:[u8;6]=[0,1,3,5,7,8];pub static C0135789:[u8;7]=[0,1,3,5,7,8,9];pub static C01356:[u8;5]=[0,1,3,5,6];pub static C013569:[u8;6]=[0,1,3,5,6,9];pub static C013568:[u8;6]=[0,1,3,5,6,8];pub static C0135689:[u8;7]=[0,1,3,5,6,8,9];pub static C013567:[u8;6]=[0,1,3,5,6,7];pub static C0135679:[u8;7]=[0,1,3,5,6,7,9];pub static C0135678:[u8;7]=[0,1,3,5,6,7,8];pub static C01356789:[u8;8]=[0,1,3,5,6,7,8,9];pub static C0134:[u8;4]=[0,1,3,4];pub static C01349:[u8;5]=[0,1,3,4,9];pub static C01348:[u8;5]=[0,1,3,4,8];pub static C013489:[u8;6]=[0,1,3,4,8,9];pub static C01347:[u8;5]=[0,1,3,4,7];pub static C013479:[u8;6]=[0,1,3,4,7,9];pub static C013478:[u8;6]=[0,1,3,4,7,8];pub static C0134789:[u8;7]=[0,1,3,4,7,8,9];pub static C01346:[u8;5]=[0,1,3,4,6];pub static C013469:[u8;6]=[0,1,3,4,6,9];pub static C013468:[u8;6]=[0,1,3,4,6,8];pub static C0134689:[u8;7]=[0,1,3,4,6,8,9];pub static C013467:[u8;6]=[0,1,3,4,6,7];pub static C0134679:[u8;7]=[0,1,3,4,6,7,9];pub static C0134678:[u8;7]=[0,1,3,4,6,7,8];pub static C01346789:[u8;8]=[0,1,3,4,6,7,8,9];pub static C01345:[u8;5]=[0,1,3,4,5];pub static C013459:[u8;6]=[0,1,3,4,5,9];pub static C013458:[u8;6]=[0,1,3,4,5,8];pub static C0134589:[u8;7]=[0,1,3,4,5,8,9];pub static C013457:[u8;6]=[0,1,3,4,5,7];pub static C0134579:[u8;7]=[0,1,3,4,5,7,9];pub static C0134578:[u8;7]=[0,1,3,4,5,7,8];pub static C01345789:[u8;8]=[0,1,3,4,5,7,8,9];pub static C013456:[u8;6]=[0,1,3,4,5,6];pub static C0134569:[u8;7]=[0,1,3,4,5,6,9];pub static C0134568:[u8;7]=[0,1,3,4,5,6,8];pub static C01345689:[u8;8]=[0,1,3,4,5,6,8,9];pub static C0134567:[u8;7]=[0,1,3,4,5,6,7];pub static C01345679:[u8;8]=[0,1,3,4,5,6,7,9];pub static C01345678:[u8;8]=[0,1,3,4,5,6,7,8];pub static C013456789:[u8;9]=[0,1,3,4,5,6,7,8,9];pub static C012:[u8;3]=[0,1,2];pub static C0129:[u8;4]=[0,1,2,9];pub static C0128:[u8;4]=[0,1,2,8];pub static C01289:[u8;5]=[0,1,2,8,9];pub static C0127:[u8;4]=[0,1,2,7];pub static C01279:[u8;5]=[0,1,2,7,9];pub static C01278:[u8;5]=[0,1,2,7,8];pub static C012789:[u8;6]=[0,1,2,7,8,9];pub static C0126:[u8;4]=[0,1,2,6];pub static C01269:[u8;5]=[0,1,2,6,9];pub static C01268:[u8;5]=[0,1,2,6,8];pub static C012689:[u8;6]=[0,1,2,6,8,9];pub static C01267:[u8;5]=[0,1,2,6,7];pub static C012679:[u8;6]=[0,1,2,6,7,9];pub static C012678:[u8;6]=[0,1,2,6,7,8];pub static C0126789:[u8;7]=[0,1,2,6,7,8,9];pub static C0125:[u8;4]=[0,1,2,5];pub static C01259:[u8;5]=[0,1,2,5,9];pub static C01258:[u8;5]=[0,1,2,5,8];pub static C012589:[u8;6]=[0,1,2,5,8,9];pub static C01257:[u8;5]=[0,1,2,5,7];pub static C012579:[u8;6]=[0,1,2,5,7,9];pub static C012578:[u8;6]=[0,1,2,5,7,8];pub static C0125789:[u8;7]=[0,1,2,5,7,8,9];pub static C01256:[u8;5]=[0,1,2,5,6];pub static C012569:[u8;6]=[0,1,2,5,6,9];pub static C012568:[u8;6]=[0,1,2,5,6,8];pub static C0125689:[u8;7]=[0,1,2,5,6,8,9];pub static C012567:[u8;6]=[0,1,2,5,6,7];pub static C0125679:[u8;7]=[0,1,2,5,6,7,9];pub static C0125678:[u8;7]=[0,1,2,5,6,7,8];pub static C01256789:[u8;8]=[0,1,2,5,6,7,8,9];pub static C0124:[u8;4]=[0,1,2,4];pub static C01249:[u8;5]=[0,1,2,4,9];pub static C01248:[u8;5]=[0,1,2,4,8];pub static C012489:[u8;6]=[0,1,2,4,8,9];pub static C01247:[u8;5]=[0,1,2,4,7];pub static C012479:[u8;6]=[0,1,2,4,7,9];pub static C012478:[u8;6]=[0,1,2,4,7,8];pub static C0124789:[u8;7]=[0,1,2,4,7,8,9];pub static C01246:[u8;5]=[0,1,2,4,6];pub static C012469:[u8;6]=[0,1,2,4,6,9];pub static C012468:[u8;6]=[0,1,2,4,6,8];pub static C0124689:[u8;7]=[0,1,2,4,6,8,9];pub static C012467:[u8;6]=[0,1,2,4,6,7];pub static C0124679:[u8;7]=[0,1,2,4,6,7,9];pub static C0124678:[u8;7]=[0,1,2,4,6,7,8];pub static C01246789:[u8;8]=[0,1,2,4,6,7,8,9];pub static C01245:[u8;5]=[0,1,2,4,5];pub static C012459:[u8;6]=[0,1,2,4,5,9];pub static C012458:[u8;6]=[0,1,2,4,5,8];pub static C0124589:[u8;7]=[0,1,2,4,5,8,9];pub static C012457:[u8;6]=[0,1,2,4,5,7];pub static C0124579:[u8;7]=[0,1,2,4,5,7,9];pub static C0124578:[u8;7]=[0,1,2,4,5,7,8];pub static C01245789:[u8;8]=[0,1,2,4,5,7,8,9];pub static C012456:[u8;6]=[0,1,2,4,5,6];pub static C0124569:[u8;7]=[0,1,2,4,5,6,9];pub static C0124568:[u8;7]=[0,1,2,4,5,6,8];pub static C01245689:[u8;8]=[0,1,2,4,5,6,8,9];pub static C0124567:[u8;7]=[0,1,2,4,5,6,7];pub static C01245679:[u8;8]=[0,1,2,4,5,6,7,9];pub static C01245678:[u8;8]=[0,1,2,4,5,6,7,8];pub static C012456789:[u8;9]=[0,1,2,4,5,6,7,8,9];pub static C0123:[u8;4]=[0,1,2,3];pub static C01239:[u8;5]=[0,1,2,3,9];pub static C01238:[u8;5]=[0,1,2,3,8];pub static C012389:[u8;6]=[0,1,2,3,8,9];pub static C01237:[u8;5]=[0,1,2,3,7];pub static C012379:[u8;6]=[0,1,2,3,7,9];pub static C012378:[u8;6]=[0,1,2,3,7,8];pub static C0123789:[u8;7]=[0,1,2,3,7,8,9];pub static C01236:[u8;5]=[0,1,2,3,6];pub static C012369:[u8;6]=[0,1,2,3,6,9];pub static C012368:[u8;6]=[0,1,2,3,6,8];pub static C0123689:[u8;7]=[0,1,2,3,6,8,9];pub static C012367:[u8;6]=[0,1,2,3,6,7];pub static C0123679:[u8;7]=[0,1,2,3,6,7,9];pub static C0123678:[u8;7]=[0,1,2,3,6,7,8];pub static C01236789:[u8;8]=[0,1,2,3,6,7,8,9];pub static C01235:[u8;5]=[0,1,2,3,5];pub static C012359:[u8;6]=[0,1,2,3,5,9];pub static C012358:[u8;6]=[0,1,2,3,5,8];pub static C0123589:[u8;7]=[0,1,2,3,5,8,9];pub static C012357:[u8;6]=[0,1,2,3,5,7];pub static C0123579:[u8;7]=[0,1,2,3,5,7,9];pub static C0123578:[u8;7]=[0,1,2,3,5,7,8];pub static C01235789:[u8;8]=[0,1,2,3,5,7,8,9];pub static C012356:[u8;6]=[0,1,2,3,5,6];pub static C0123569:[u8;7]=[0,1,2,3,5,6,9];pub static C0123568:[u8;7]=[0,1,2,3,5,6,8];pub static C01235689:[u8;8]=[0,1,2,3,5,6,8,9];pub static C0123567:[u8;7]=[0,1,2,3,5,6,7];pub static C01235679:[u8;8]=[0,1,2,3,5,6,7,9];pub static C01235678:[u8;8]=[0,1,2,3,5,6,7,8];pub static C012356789:[u8;9]=[0,1,2,3,5,6,7,8,9];pub static C01234:[u8;5]=[0,1,2,3,4];pub static C012349:[u8;6]=[0,1,2,3,4,9];pub static C012348:[u8;6]=[0,1,2,3,4,8];pub static C0123489:[u8;7]=[0,1,2,3,4,8,9];pub static C012347:[u8;6]=[0,1,2,3,4,7];pub static C0123479:[u8;7]=[0,1,2,3,4,7,9];pub static C0123478:[u8;7]=[0,1,2,3,4,7,8];pub static C01234789:[u8;8]=[0,1,2,3,4,7,8,9];pub static C012346:[u8;6]=[0,1,2,3,4,6];pub static C0123469:[u8;7]=[0,1,2,3,4,6,9];pub static C0123468:[u8;7]=[0,1,2,3,4,6,8];pub static C01234689:[u8;8]=[0,1,2,3,4,6,8,9];pub static C0123467:[u8;7]=[0,1,2,3,4,6,7];pub static C01234679:[u8;8]=[0,1,2,3,4,6,7,9];pub static C01234678:[u8;8]=[0,1,2,3,4,6,7,8];pub static C012346789:[u8;9]=[0,1,2,3,4,6,7,8,9];pub static C012345:[u8;6]=[0,1,2,3,4,5];pub static C0123459:[u8;7]=[0,1,2,3,4,5,9];pub static C0123458:[u8;7]=[0,1,2,3,4,5,8];pub static C01234589:[u8;8]=[0,1,2,3,4,5,8,9];pub static C0123457:[u8;7]=[0,1,2,3,4,5,7];pub static C01234579:[u8;8]=[0,1,2,3,4,5,7,9];pub static C01234578:[u8;8]=[0,1,2,3,4,5,7,8];pub static C012345789:[u8;9]=[0,1,2,3,4,5,7,8,9];pub static C0123456:[u8;7]=[0,1,2,3,4,5,6];pub static C01234569:[u8;8]=[0,1,2,3,4,5,6,9];pub static C01234568:[u8;8]=[0,1,2,3,4,5,6,8];pub static C012345689:[u8;9]=[0,1,2,3,4,5,6,8,9];pub static C01234567:[u8;8]=[0,1,2,3,4,5,6,7];pub static C012345679:[u8;9]=[0,1,2,3,4,5,6,7,9];pub static C012345678:[u8;9]=[0,1,2,3,4,5,6,7,8];pub static C0123456789:[u8;10]=[0,1,2,3,4,5,6,7,8,9];
-pub static DIST:[&[(&[u8],&[u8])];8]=[&[(&C2,&C01),(&C1,&C02),(&C0,&C12),],&[(&C3,&C012),(&C2,&C013),(&C23,&C01),(&C1,&C023),(&C13,&C02),(&C12,&C03),(&C0,&C123),(&C03,&C12),(&C02,&C13),(&C01,&C23),],&[(&C4,&C0123),(&C3,&C0124),(&C34,&C012),(&C2,&C0134),(&C24,&C013),(&C23,&C014),(&C1,&C0234),(&C14,&C023),(&C13,&C024),(&C12,&C034),(&C0,&C1234),(&C04,&C123),(&C03,&C124),(&C02,&C134),(&C01,&C234),],&[(&C5,&C01234),(&C4,&C01235),(&C45,&C0123),(&C3,&C01245),(&C35,&C0124),(&C34,&C0125),(&C345,&C012),(&C2,&C01345),(&C25,&C0134),(&C24,&C0135),(&C245,&C013),(&C23,&C0145),(&C235,&C014),(&C234,&C015),(&C1,&C02345),(&C15,&C0234),(&C14,&C0235),(&C145,&C023),(&C13,&C0245),(&C135,&C024),(&C134,&C025),(&C12,&C0345),(&C125,&C034),(&C124,&C035),(&C123,&C045),(&C0,&C12345),(&C05,&C1234),(&C04,&C1235),(&C045,&C123),(&C03,&C1245),(&C035,&C124),(&C034,&C125),(&C02,&C1345),(&C025,&C134),(&C024,&C135),(&C023,&C145),(&C01,&C2345),(&C015,&C234),(&C014,&C235),(&C013,&C245),(&C012,&C345),],&[(&C6,&C012345),(&C5,&C012346),(&C56,&C01234),(&C4,&C012356),(&C46,&C01235),(&C45,&C01236),(&C456,&C0123),(&C3,&C012456),(&C36,&C01245),(&C35,&C01246),(&C356,&C0124),(&C34,&C01256),(&C346,&C0125),(&C345,&C0126),(&C2,&C013456),(&C26,&C01345),(&C25,&C01346),(&C256,&C0134),(&C24,&C01356),(&C246,&C0135),(&C245,&C0136),(&C23,&C01456),(&C236,&C0145),(&C235,&C0146),(&C234,&C0156),(&C1,&C023456),(&C16,&C02345),(&C15,&C02346),(&C156,&C0234),(&C14,&C02356),(&C146,&C0235),(&C145,&C0236),(&C13,&C02456),(&C136,&C0245),(&C135,&C0246),(&C134,&C0256),(&C12,&C03456),(&C126,&C0345),(&C125,&C0346),(&C124,&C0356),(&C123,&C0456),(&C0,&C123456),(&C06,&C12345),(&C05,&C12346),(&C056,&C1234),(&C04,&C12356),(&C046,&C1235),(&C045,&C1236),(&C03,&C12456),(&C036,&C1245),(&C035,&C1246),(&C034,&C1256),(&C02,&C13456),(&C026,&C1345),(&C025,&C1346),(&C024,&C1356),(&C023,&C1456),(&C01,&C23456),(&C016,&C2345),(&C015,&C2346),(&C014,&C2356),(&C013,&C2456),(&C012,&C3456),],&[(&C7,&C0123456),(&C6,&C0123457),(&C67,&C012345),(&C5,&C0123467),(&C57,&C012346),(&C56,&C012347),(&C567,&C01234),(&C4,&C0123567),(&C47,&C012356),(&C46,&C012357),(&C467,&C01235),(&C45,&C012367),(&C457,&C01236),(&C456,&C01237),(&C4567,&C0123),(&C3,&C0124567),(&C37,&C012456),(&C36,&C012457),(&C367,&C01245),(&C35,&C012467),(&C357,&C01246),(&C356,&C01247),(&C3567,&C0124),(&C34,&C012567),(&C347,&C01256),(&C346,&C01257),(&C3467,&C0125),(&C345,&C01267),(&C3457,&C0126),(&C3456,&C0127),(&C2,&C0134567),(&C27,&C013456),(&C26,&C013457),(&C267,&C01345),(&C25,&C013467),(&C257,&C01346),(&C256,&C01347),(&C2567,&C0134),(&C24,&C013567),(&C247,&C01356),(&C246,&C01357),(&C2467,&C0135),(&C245,&C01367),(&C2457,&C0136),(&C2456,&C0137),(&C23,&C014567),(&C237,&C01456),(&C236,&C01457),(&C2367,&C0145),(&C235,&C01467),(&C2357,&C0146),(&C2356,&C0147),(&C234,&C01567),(&C2347,&C0156),(&C2346,&C0157),(&C2345,&C0167),(&C1,&C0234567),(&C17,&C023456),(&C16,&C023457),(&C167,&C02345),(&C15,&C023467),(&C157,&C02346),(&C156,&C02347),(&C1567,&C0234),(&C14,&C023567),(&C147,&C02356),(&C146,&C02357),(&C1467,&C0235),(&C145,&C02367),(&C1457,&C0236),(&C1456,&C0237),(&C13,&C024567),(&C137,&C02456),(&C136,&C02457),(&C1367,&C0245),(&C135,&C02467),(&C1357,&C0246),(&C1356,&C0247),(&C134,&C02567),(&C1347,&C0256),(&C1346,&C0257),(&C1345,&C0267),(&C12,&C034567),(&C127,&C03456),(&C126,&C03457),(&C1267,&C0345),(&C125,&C03467),(&C1257,&C0346),(&C1256,&C0347),(&C124,&C03567),(&C1247,&C0356),(&C1246,&C0357),(&C1245,&C0367),(&C123,&C04567),(&C1237,&C0456),(&C1236,&C0457),(&C1235,&C0467),(&C1234,&C0567),(&C0,&C1234567),(&C07,&C123456),(&C06,&C123457),(&C067,&C12345),(&C05,&C123467),(&C057,&C12346),(&C056,&C12347),(&C0567,&C1234),(&C04,&C123567),(&C047,&C12356),(&C046,&C12357),(&C0467,&C1235),(&C045,&C12367),(&C0457,&C1236),(&C0456,&C1237),(&C03,&C124567),(&C037,&C12456),(&C036,&C12457),(&C0367,&C1245),(&C035,&C12467),(&C0357,&C1246),(&C0356,&C1247),(&C034,&C12567),(&C0347,&C1256),(&C0346,&C1257),(&C0345,&C1267),(&C02,&C134567),(&C027,&C13456),(&C026,&C13457),(&C0267,&C1345),(&C025,&C13467),(&C0257,&C1346),(&C0256,&C1347),(&C024,&C13567),(&C0247,&C1356),(&C0246,&C1357),(&C0245,&C1367),(&C023,&C14567),(&C0237,&C1456),(&C0236,&C1457),(&C0235,&C1467),(&C0234,&C1567),(&C01,&C234567),(&C017,&C23456),(&C016,&C23457),(&C0167,&C2345),(&C015,&C23467),(&C0157,&C2346),(&C0156,&C2347),(&C014,&C23567),(&C0147,&C2356),(&C0146,&C2357),(&C0145,&C2367),(&C013,&C24567),(&C0137,&C2456),(&C0136,&C2457),(&C0135,&C2467),(&C0134,&C2567),(&C012,&C34567),(&C0127,&C3456),(&C0126,&C3457),(&C0125,&C3467),(&C0124,&C3567),(&C0123,&C4567),],&[(&C8,&C01234567),(&C7,&C01234568),(&C78,&C0123456),(&C6,&C01234578),(&C68,&C0123457),(&C67,&C0123458),(&C678,&C012345),(&C5,&C01234678),(&C58,&C0123467),(&C57,&C0123468),(&C578,&C012346),(&C56,&C0123478),(&C568,&C012347),(&C567,&C012348),(&C5678,&C01234),(&C4,&C01235678),(&C48,&C0123567),(&C47,&C0123568),(&C478,&C012356),(&C46,&C0123578),(&C468,&C012357),(&C467,&C012358),(&C4678,&C01235),(&C45,&C0123678),(&C458,&C012367),(&C457,&C012368),(&C4578,&C01236),(&C456,&C012378),(&C4568,&C01237),(&C4567,&C01238),(&C3,&C01245678),(&C38,&C0124567),(&C37,&C0124568),(&C378,&C012456),(&C36,&C0124578),(&C368,&C012457),(&C367,&C012458),(&C3678,&C01245),(&C35,&C0124678),(&C358,&C012467),(&C357,&C012468),(&C3578,&C01246),(&C356,&C012478),(&C3568,&C01247),(&C3567,&C01248),(&C34,&C0125678),(&C348,&C012567),(&C347,&C012568),(&C3478,&C01256),(&C346,&C012578),(&C3468,&C01257),(&C3467,&C01258),(&C345,&C012678),(&C3458,&C01267),(&C3457,&C01268),(&C3456,&C01278),(&C2,&C01345678),(&C28,&C0134567),(&C27,&C0134568),(&C278,&C013456),(&C26,&C0134578),(&C268,&C013457),(&C267,&C013458),(&C2678,&C01345),(&C25,&C0134678),(&C258,&C013467),(&C257,&C013468),(&C2578,&C01346),(&C256,&C013478),(&C2568,&C01347),(&C2567,&C01348),(&C24,&C0135678),(&C248,&C013567),(&C247,&C013568),(&C2478,&C01356),(&C246,&C013578),(&C2468,&C01357),(&C2467,&C01358),(&C245,&C013678),(&C2458,&C01367),(&C2457,&C01368),(&C2456,&C01378),(&C23,&C0145678),(&C238,&C014567),(&C237,&C014568),(&C2378,&C01456),(&C236,&C014578),(&C2368,&C01457),(&C2367,&C01458),(&C235,&C014678),(&C2358,&C01467),(&C2357,&C01468),(&C2356,&C01478),(&C234,&C015678),(&C2348,&C01567),(&C2347,&C01568),(&C2346,&C01578),(&C2345,&C01678),(&C1,&C02345678),(&C18,&C0234567),(&C17,&C0234568),(&C178,&C023456),(&C16,&C0234578),(&C168,&C023457),(&C167,&C023458),(&C1678,&C02345),(&C15,&C0234678),(&C158,&C023467),(&C157,&C023468),(&C1578,&C02346),(&C156,&C023478),(&C1568,&C02347),(&C1567,&C02348),(&C14,&C0235678),(&C148,&C023567),(&C147,&C023568),(&C1478,&C02356),(&C146,&C023578),(&C1468,&C02357),(&C1467,&C02358),(&C145,&C023678),(&C1458,&C02367),(&C1457,&C02368),(&C1456,&C02378),(&C13,&C0245678),(&C138,&C024567),(&C137,&C024568),(&C1378,&C02456),(&C136,&C024578),(&C1368,&C02457),(&C1367,&C02458),(&C135,&C024678),(&C1358,&C02467),(&C1357,&C02468),(&C1356,&C02478),(&C134,&C025678),(&C1348,&C02567),(&C1347,&C02568),(&C1346,&C02578),(&C1345,&C02678),(&C12,&C0345678),(&C128,&C034567),(&C127,&C034568),(&C1278,&C03456),(&C126,&C034578),(&C1268,&C03457),(&C1267,&C03458),(&C125,&C034678),(&C1258,&C03467),(&C1257,&C03468),(&C1256,&C03478),(&C124,&C035678),(&C1248,&C03567),(&C1247,&C03568),(&C1246,&C03578),(&C1245,&C03678),(&C123,&C045678),(&C1238,&C04567),(&C1237,&C04568),(&C1236,&C04578),(&C1235,&C04678),(&C1234,&C05678),(&C0,&C12345678),(&C08,&C1234567),(&C07,&C1234568),(&C078,&C123456),(&C06,&C1234578),(&C068,&C123457),(&C067,&C123458),(&C0678,&C12345),(&C05,&C1234678),(&C058,&C123467),(&C057,&C123468),(&C0578,&C12346),(&C056,&C123478),(&C0568,&C12347),(&C0567,&C12348),(&C04,&C1235678),(&C048,&C123567),(&C047,&C123568),(&C0478,&C12356),(&C046,&C123578),(&C0468,&C12357),(&C0467,&C12358),(&C045,&C123678),(&C0458,&C12367),(&C0457,&C12368),(&C0456,&C12378),(&C03,&C1245678),(&C038,&C124567),(&C037,&C124568),(&C0378,&C12456),(&C036,&C124578),(&C0368,&C12457),(&C0367,&C12458),(&C035,&C124678),(&C0358,&C12467),(&C0357,&C12468),(&C0356,&C12478),(&C034,&C125678),(&C0348,&C12567),(&C0347,&C12568),(&C0346,&C12578),(&C0345,&C12678),(&C02,&C1345678),(&C028,&C134567),(&C027,&C134568),(&C0278,&C13456),(&C026,&C134578),(&C0268,&C13457),(&C0267,&C13458),(&C025,&C134678),(&C0258,&C13467),(&C0257,&C13468),(&C0256,&C13478),(&C024,&C135678),(&C0248,&C13567),(&C0247,&C13568),(&C0246,&C13578),(&C0245,&C13678),(&C023,&C145678),(&C0238,&C14567),(&C0237,&C14568),(&C0236,&C14578),(&C0235,&C14678),(&C0234,&C15678),(&C01,&C2345678),(&C018,&C234567),(&C017,&C234568),(&C0178,&C23456),(&C016,&C234578),(&C0168,&C23457),(&C0167,&C23458),(&C015,&C234678),(&C0158,&C23467),(&C0157,&C23468),(&C0156,&C23478),(&C014,&C235678),(&C0148,&C23567),(&C0147,&C23568),(&C0146,&C23578),(&C0145,&C23678),(&C013,&C245678),(&C0138,&C24567),(&C0137,&C24568),(&C0136,&C24578),(&C0135,&C24678),(&C0134,&C25678),(&C012,&C345678),(&C0128,&C34567),(&C0127,&C34568),(&C0126,&C34578),(&C0125,&C34678),(&C0124,&C35678),(&C0123,&C45678),],&[(&C9,&C012345678),(&C8,&C012345679),(&C89,&C01234567),(&C7,&C012345689),(&C79,&C01234568),(&C78,&C01234569),(&C789,&C0123456),(&C6,&C012345789),(&C69,&C01234578),(&C68,&C01234579),(&C689,&C0123457),(&C67,&C01234589),(&C679,&C0123458),(&C678,&C0123459),(&C6789,&C012345),(&C5,&C012346789),(&C59,&C01234678),(&C58,&C01234679),(&C589,&C0123467),(&C57,&C01234689),(&C579,&C0123468),(&C578,&C0123469),(&C5789,&C012346),(&C56,&C01234789),(&C569,&C0123478),(&C568,&C0123479),(&C5689,&C012347),(&C567,&C0123489),(&C5679,&C012348),(&C5678,&C012349),(&C56789,&C01234),(&C4,&C012356789),(&C49,&C01235678),(&C48,&C01235679),(&C489,&C0123567),(&C47,&C01235689),(&C479,&C0123568),(&C478,&C0123569),(&C4789,&C012356),(&C46,&C01235789),(&C469,&C0123578),(&C468,&C0123579),(&C4689,&C012357),(&C467,&C0123589),(&C4679,&C012358),(&C4678,&C012359),(&C46789,&C01235),(&C45,&C01236789),(&C459,&C0123678),(&C458,&C0123679),(&C4589,&C012367),(&C457,&C0123689),(&C4579,&C012368),(&C4578,&C012369),(&C45789,&C01236),(&C456,&C0123789),(&C4569,&C012378),(&C4568,&C012379),(&C45689,&C01237),(&C4567,&C012389),(&C45679,&C01238),(&C45678,&C01239),(&C3,&C012456789),(&C39,&C01245678),(&C38,&C01245679),(&C389,&C0124567),(&C37,&C01245689),(&C379,&C0124568),(&C378,&C0124569),(&C3789,&C012456),(&C36,&C01245789),(&C369,&C0124578),(&C368,&C0124579),(&C3689,&C012457),(&C367,&C0124589),(&C3679,&C012458),(&C3678,&C012459),(&C36789,&C01245),(&C35,&C01246789),(&C359,&C0124678),(&C358,&C0124679),(&C3589,&C012467),(&C357,&C0124689),(&C3579,&C012468),(&C3578,&C012469),(&C35789,&C01246),(&C356,&C0124789),(&C3569,&C012478),(&C3568,&C012479),(&C35689,&C01247),(&C3567,&C012489),(&C35679,&C01248),(&C35678,&C01249),(&C34,&C01256789),(&C349,&C0125678),(&C348,&C0125679),(&C3489,&C012567),(&C347,&C0125689),(&C3479,&C012568),(&C3478,&C012569),(&C34789,&C01256),(&C346,&C0125789),(&C3469,&C012578),(&C3468,&C012579),(&C34689,&C01257),(&C3467,&C012589),(&C34679,&C01258),(&C34678,&C01259),(&C345,&C0126789),(&C3459,&C012678),(&C3458,&C012679),(&C34589,&C01267),(&C3457,&C012689),(&C34579,&C01268),(&C34578,&C01269),(&C3456,&C012789),(&C34569,&C01278),(&C34568,&C01279),(&C34567,&C01289),(&C2,&C013456789),(&C29,&C01345678),(&C28,&C01345679),(&C289,&C0134567),(&C27,&C01345689),(&C279,&C0134568),(&C278,&C0134569),(&C2789,&C013456),(&C26,&C01345789),(&C269,&C0134578),(&C268,&C0134579),(&C2689,&C013457),(&C267,&C0134589),(&C2679,&C013458),(&C2678,&C013459),(&C26789,&C01345),(&C25,&C01346789),(&C259,&C0134678),(&C258,&C0134679),(&C2589,&C013467),(&C257,&C0134689),(&C2579,&C013468),(&C2578,&C013469),(&C25789,&C01346),(&C256,&C0134789),(&C2569,&C013478),(&C2568,&C013479),(&C25689,&C01347),(&C2567,&C013489),(&C25679,&C01348),(&C25678,&C01349),(&C24,&C01356789),(&C249,&C0135678),(&C248,&C0135679),(&C2489,&C013567),(&C247,&C0135689),(&C2479,&C013568),(&C2478,&C013569),(&C24789,&C01356),(&C246,&C0135789),(&C2469,&C013578),(&C2468,&C013579),(&C24689,&C01357),(&C2467,&C013589),(&C24679,&C01358),(&C24678,&C01359),(&C245,&C0136789),(&C2459,&C013678),(&C2458,&C013679),(&C24589,&C01367),(&C2457,&C013689),(&C24579,&C01368),(&C24578,&C01369),(&C2456,&C013789),(&C24569,&C01378),(&C24568,&C01379),(&C24567,&C01389),(&C23,&C01456789),(&C239,&C0145678),(&C238,&C0145679),(&C2389,&C014567),(&C237,&C0145689),(&C2379,&C014568),(&C2378,&C014569),(&C23789,&C01456),(&C236,&C0145789),(&C2369,&C014578),(&C2368,&C014579),(&C23689,&C01457),(&C2367,&C014589),(&C23679,&C01458),(&C23678,&C01459),(&C235,&C0146789),(&C2359,&C014678),(&C2358,&C014679),(&C23589,&C01467),(&C2357,&C014689),(&C23579,&C01468),(&C23578,&C01469),(&C2356,&C014789),(&C23569,&C01478),(&C23568,&C01479),(&C23567,&C01489),(&C234,&C0156789),(&C2349,&C015678),(&C2348,&C015679),(&C23489,&C01567),(&C2347,&C015689),(&C23479,&C01568),(&C23478,&C01569),(&C2346,&C015789),(&C23469,&C01578),(&C23468,&C01579),(&C23467,&C01589),(&C2345,&C016789),(&C23459,&C01678),(&C23458,&C01679),(&C23457,&C01689),(&C23456,&C01789),(&C1,&C023456789),(&C19,&C02345678),(&C18,&C02345679),(&C189,&C0234567),(&C17,&C02345689),(&C179,&C0234568),(&C178,&C0234569),(&C1789,&C023456),(&C16,&C02345789),(&C169,&C0234578),(&C168,&C0234579),(&C1689,&C023457),(&C167,&C0234589),(&C1679,&C023458),(&C1678,&C023459),(&C16789,&C02345),(&C15,&C02346789),(&C159,&C0234678),(&C158,&C0234679),(&C1589,&C023467),(&C157,&C0234689),(&C1579,&C023468),(&C1578,&C023469),(&C15789,&C02346),(&C156,&C0234789),(&C1569,&C023478),(&C1568,&C023479),(&C15689,&C02347),(&C1567,&C023489),(&C15679,&C02348),(&C15678,&C02349),(&C14,&C02356789),(&C149,&C0235678),(&C148,&C0235679),(&C1489,&C023567),(&C147,&C0235689),(&C1479,&C023568),(&C1478,&C023569),(&C14789,&C02356),(&C146,&C0235789),(&C1469,&C023578),(&C1468,&C023579),(&C14689,&C02357),(&C1467,&C023589),(&C14679,&C02358),(&C14678,&C02359),(&C145,&C0236789),(&C1459,&C023678),(&C1458,&C023679),(&C14589,&C02367),(&C1457,&C023689),(&C14579,&C02368),(&C14578,&C02369),(&C1456,&C023789),(&C14569,&C02378),(&C14568,&C02379),(&C14567,&C02389),(&C13,&C02456789),(&C139,&C0245678),(&C138,&C0245679),(&C1389,&C024567),(&C137,&C0245689),(&C1379,&C024568),(&C1378,&C024569),(&C13789,&C02456),(&C136,&C0245789),(&C1369,&C024578),(&C1368,&C024579),(&C13689,&C02457),(&C1367,&C024589),(&C13679,&C02458),(&C13678,&C02459),(&C135,&C0246789),(&C1359,&C024678),(&C1358,&C024679),(&C13589,&C02467),(&C1357,&C024689),(&C13579,&C02468),(&C13578,&C02469),(&C1356,&C024789),(&C13569,&C02478),(&C13568,&C02479),(&C13567,&C02489),(&C134,&C0256789),(&C1349,&C025678),(&C1348,&C025679),(&C13489,&C02567),(&C1347,&C025689),(&C13479,&C02568),(&C13478,&C02569),(&C1346,&C025789),(&C13469,&C02578),(&C13468,&C02579),(&C13467,&C02589),(&C1345,&C026789),(&C13459,&C02678),(&C13458,&C02679),(&C13457,&C02689),(&C13456,&C02789),(&C12,&C03456789),(&C129,&C0345678),(&C128,&C0345679),(&C1289,&C034567),(&C127,&C0345689),(&C1279,&C034568),(&C1278,&C034569),(&C12789,&C03456),(&C126,&C0345789),(&C1269,&C034578),(&C1268,&C034579),(&C12689,&C03457),(&C1267,&C034589),(&C12679,&C03458),(&C12678,&C03459),(&C125,&C0346789),(&C1259,&C034678),(&C1258,&C034679),(&C12589,&C03467),(&C1257,&C034689),(&C12579,&C03468),(&C12578,&C03469),(&C1256,&C034789),(&C12569,&C03478),(&C12568,&C03479),(&C12567,&C03489),(&C124,&C0356789),(&C1249,&C035678),(&C1248,&C035679),(&C12489,&C03567),(&C1247,&C035689),(&C12479,&C03568),(&C12478,&C03569),(&C1246,&C035789),(&C12469,&C03578),(&C12468,&C03579),(&C12467,&C03589),(&C1245,&C036789),(&C12459,&C03678),(&C12458,&C03679),(&C12457,&C03689),(&C12456,&C03789),(&C123,&C0456789),(&C1239,&C045678),(&C1238,&C045679),(&C12389,&C04567),(&C1237,&C045689),(&C12379,&C04568),(&C12378,&C04569),(&C1236,&C045789),(&C12369,&C04578),(&C12368,&C04579),(&C12367,&C04589),(&C1235,&C046789),(&C12359,&C04678),(&C12358,&C04679),(&C12357,&C04689),(&C12356,&C04789),(&C1234,&C056789),(&C12349,&C05678),(&C12348,&C05679),(&C12347,&C05689),(&C12346,&C05789),(&C12345,&C06789),(&C0,&C123456789),(&C09,&C12345678),(&C08,&C12345679),(&C089,&C1234567),(&C07,&C12345689),(&C079,&C1234568),(&C078,&C1234569),(&C0789,&C123456),(&C06,&C12345789),(&C069,&C1234578),(&C068,&C1234579),(&C0689,&C123457),(&C067,&C1234589),(&C0679,&C123458),(&C0678,&C123459),(&C06789,&C12345),(&C05,&C12346789),(&C059,&C1234678),(&C058,&C1234679),(&C0589,&C123467),(&C057,&C1234689),(&C0579,&C123468),(&C0578,&C123469),(&C05789,&C12346),(&C056,&C1234789),(&C0569,&C123478),(&C0568,&C123479),(&C05689,&C12347),(&C0567,&C123489),(&C05679,&C12348),(&C05678,&C12349),(&C04,&C12356789),(&C049,&C1235678),(&C048,&C1235679),(&C0489,&C123567),(&C047,&C1235689),(&C0479,&C123568),(&C0478,&C123569),(&C04789,&C12356),(&C046,&C1235789),(&C0469,&C123578),(&C0468,&C123579),(&C04689,&C12357),(&C0467,&C123589),(&C04679,&C12358),(&C04678,&C12359),(&C045,&C1236789),(&C0459,&C123678),(&C0458,&C123679),(&C04589,&C12367),(&C0457,&C123689),(&C04579,&C12368),(&C04578,&C12369),(&C0456,&C123789),(&C04569,&C12378),(&C04568,&C12379),(&C04567,&C12389),(&C03,&C12456789),(&C039,&C1245678),(&C038,&C1245679),(&C0389,&C124567),(&C037,&C1245689),(&C0379,&C124568),(&C0378,&C124569),(&C03789,&C12456),(&C036,&C1245789),(&C0369,&C124578),(&C0368,&C124579),(&C03689,&C12457),(&C0367,&C124589),(&C03679,&C12458),(&C03678,&C12459),(&C035,&C1246789),(&C0359,&C124678),(&C0358,&C124679),(&C03589,&C12467),(&C0357,&C124689),(&C03579,&C12468),(&C03578,&C12469),(&C0356,&C124789),(&C03569,&C12478),(&C03568,&C12479),(&C03567,&C12489),(&C034,&C1256789),(&C0349,&C125678),(&C0348,&C125679),(&C03489,&C12567),(&C0347,&C125689),(&C03479,&C12568),(&C03478,&C12569),(&C0346,&C125789),(&C03469,&C12578),(&C03468,&C12579),(&C03467,&C12589),(&C0345,&C126789),(&C03459,&C12678),(&C03458,&C12679),(&C03457,&C12689),(&C03456,&C12789),(&C02,&C13456789),(&C029,&C1345678),(&C028,&C1345679),(&C0289,&C134567),(&C027,&C1345689),(&C0279,&C134568),(&C0278,&C134569),(&C02789,&C13456),(&C026,&C1345789),(&C0269,&C134578),(&C0268,&C134579),(&C02689,&C13457),(&C0267,&C134589),(&C02679,&C13458),(&C02678,&C13459),(&C025,&C1346789),(&C0259,&C134678),(&C0258,&C134679),(&C02589,&C13467),(&C0257,&C134689),(&C02579,&C13468),(&C02578,&C13469),(&C0256,&C134789),(&C02569,&C13478),(&C02568,&C13479),(&C02567,&C13489),(&C024,&C1356789),(&C0249,&C135678),(&C0248,&C135679),(&C02489,&C13567),(&C0247,&C135689),(&C02479,&C13568),(&C02478,&C13569),(&C0246,&C135789),(&C02469,&C13578),(&C02468,&C13579),(&C02467,&C13589),(&C0245,&C136789),(&C02459,&C13678),(&C02458,&C13679),(&C02457,&C13689),(&C02456,&C13789),(&C023,&C1456789),(&C0239,&C145678),(&C0238,&C145679),(&C02389,&C14567),(&C0237,&C145689),(&C02379,&C14568),(&C02378,&C14569),(&C0236,&C145789),(&C02369,&C14578),(&C02368,&C14579),(&C02367,&C14589),(&C0235,&C146789),(&C02359,&C14678),(&C02358,&C14679),(&C02357,&C14689),(&C02356,&C14789),(&C0234,&C156789),(&C02349,&C15678),(&C02348,&C15679),(&C02347,&C15689),(&C02346,&C15789),(&C02345,&C16789),(&C01,&C23456789),(&C019,&C2345678),(&C018,&C2345679),(&C0189,&C234567),(&C017,&C2345689),(&C0179,&C234568),(&C0178,&C234569),(&C01789,&C23456),(&C016,&C2345789),(&C0169,&C234578),(&C0168,&C234579),(&C01689,&C23457),(&C0167,&C234589),(&C01679,&C23458),(&C01678,&C23459),(&C015,&C2346789),(&C0159,&C234678),(&C0158,&C234679),(&C01589,&C23467),(&C0157,&C234689),(&C01579,&C23468),(&C01578,&C23469),(&C0156,&C234789),(&C01569,&C23478),(&C01568,&C23479),(&C01567,&C23489),(&C014,&C2356789),(&C0149,&C235678),(&C0148,&C235679),(&C01489,&C23567),(&C0147,&C235689),(&C01479,&C23568),(&C01478,&C23569),(&C0146,&C235789),(&C01469,&C23578),(&C01468,&C23579),(&C01467,&C23589),(&C0145,&C236789),(&C01459,&C23678),(&C01458,&C23679),(&C01457,&C23689),(&C01456,&C23789),(&C013,&C2456789),(&C0139,&C245678),(&C0138,&C245679),(&C01389,&C24567),(&C0137,&C245689),(&C01379,&C24568),(&C01378,&C24569),(&C0136,&C245789),(&C01369,&C24578),(&C01368,&C24579),(&C01367,&C24589),(&C0135,&C246789),(&C01359,&C24678),(&C01358,&C24679),(&C01357,&C24689),(&C01356,&C24789),(&C0134,&C256789),(&C01349,&C25678),(&C01348,&C25679),(&C01347,&C25689),(&C01346,&C25789),(&C01345,&C26789),(&C012,&C3456789),(&C0129,&C345678),(&C0128,&C345679),(&C01289,&C34567),(&C0127,&C345689),(&C01279,&C34568),(&C01278,&C34569),(&C0126,&C345789),(&C01269,&C34578),(&C01268,&C34579),(&C01267,&C34589),(&C0125,&C346789),(&C01259,&C34678),(&C01258,&C34679),(&C01257,&C34689),(&C01256,&C34789),(&C0124,&C356789),(&C01249,&C35678),(&C01248,&C35679),(&C01247,&C35689),(&C01246,&C35789),(&C01245,&C36789),(&C0123,&C456789),(&C01239,&C45678),(&C01238,&C45679),(&C01237,&C45689),(&C01236,&C45789),(&C01235,&C46789),(&C01234,&C56789),],];
+//! The splits of up to [`MAX_ITEMS`] items, computed at compile time.
+
+/// The most items whose splits are kept in a table. It is 10 unless raised by the `max-items-12`,
+/// `max-items-14` or `max-items-16` feature. More items are split on demand, which is a bit slower.
+pub const MAX_ITEMS: usize = if cfg!(feature = "max-items-16") {
+    16
+} else if cfg!(feature = "max-items-14") {
+    14
+} else if cfg!(feature = "max-items-12") {
+    12
+} else {
+    10
+};
+
+/// Where the splits of each count of items start in `DIST`, from 3 items, followed by the end.
+const OFFSETS: [usize; MAX_ITEMS - 1] = {
+    let mut offsets = [0; MAX_ITEMS - 1];
+    let mut count = 3;
+
+    while count <= MAX_ITEMS {
+        offsets[count - 2] = offsets[count - 3] + split_count(count);
+        count += 1;
+    }
+
+    offsets
+};
+
+/// The left side of every split of 3 to [`MAX_ITEMS`] items, as a bit mask of positions. The right
+/// side is every other item.
+static DIST: [u32; OFFSETS[MAX_ITEMS - 2]] = {
+    let mut dist = [0; OFFSETS[MAX_ITEMS - 2]];
+    let mut count = 3;
+
+    while count <= MAX_ITEMS {
+        let mut index = OFFSETS[count - 3];
+        let mut subset = 1_u32;
+
+        // The first item is the highest bit of the subset, so the order matches the power set.
+        while subset < 1 << count {
+            if is_left(subset, count) {
+                dist[index] = subset.reverse_bits() >> (u32::BITS as usize - count);
+                index += 1;
+            }
+
+            subset += 1;
+        }
+
+        count += 1;
+    }
+
+    dist
+};
+
+/// The left sides of the splits of `count` items, from 3 to [`MAX_ITEMS`].
+pub fn dist(count: usize) -> &'static [u32] {
+    &DIST[OFFSETS[count - 3]..OFFSETS[count - 2]]
+}
+
+/// Whether `subset` is on the left of a split of `count` items, which is never larger than the
+/// right, so parts of equal size appear in both orders.
+const fn is_left(subset: u32, count: usize) -> bool {
+    subset.count_ones() as usize * 2 <= count
+}
+
+const fn split_count(count: usize) -> usize {
+    let mut splits = 0;
+    let mut subset = 1_u32;
+
+    while subset < 1 << count {
+        if is_left(subset, count) {
+            splits += 1;
+        }
+
+        subset += 1;
+    }
+
+    splits
+}
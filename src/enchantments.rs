@@ -1,5 +1,7 @@
+use std::fmt::{Display, Formatter};
 use crate::bits::{EnchantmentMask, ItemMask};
 use crate::items::ItemKind;
+use crate::rules::Edition;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Enchantment {
@@ -8,6 +10,7 @@ pub struct Enchantment {
 }
 
 impl Enchantment {
+    /// Returns `None` if the level is above what can be obtained in survival.
     #[inline]
     pub const fn new(kind: EnchantmentKind, level: u8) -> Option<Self> {
         if level > kind.max_level() {
@@ -17,6 +20,17 @@ impl Enchantment {
         }
     }
 
+    /// Creates an enchantment with any level, like the ones handed out by commands or plugins.
+    #[inline]
+    pub const fn custom(kind: EnchantmentKind, level: u8) -> Self {
+        Self { kind, level }
+    }
+
+    #[inline]
+    pub const fn is_above_maximum(self) -> bool {
+        self.level > self.kind.max_level()
+    }
+
     #[inline]
     pub const fn kind(self) -> EnchantmentKind {
         self.kind
@@ -28,6 +42,23 @@ impl Enchantment {
     }
 }
 
+impl Display for Enchantment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} ", self.kind)?;
+
+        let mut level = self.level;
+
+        for (value, numeral) in [(100, "C"), (90, "XC"), (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")] {
+            while level >= value {
+                f.write_str(numeral)?;
+                level -= value;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[repr(u8)]
@@ -139,7 +170,32 @@ impl EnchantmentKind {
         }
     }
 
-    pub fn applicable_to(self) -> ItemMask {
+    /// The items an anvil applies the enchantment to. Impaling and the enchantments of axes are
+    /// the same in both editions, only their effects differ.
+    pub fn applicable_to(self, edition: Edition) -> ItemMask {
+        match edition {
+            Edition::Java => self.java_applicable_to(),
+            Edition::Bedrock => match self {
+                // Sweeping Edge does not exist in Bedrock Edition.
+                EnchantmentKind::SweepingEdge => ItemMask::empty(),
+                _ => self.java_applicable_to(),
+            },
+        }
+    }
+
+    pub fn incompatible_with(self, edition: Edition) -> EnchantmentMask {
+        match edition {
+            Edition::Java => self.java_incompatible_with(),
+            Edition::Bedrock => match self {
+                // Bows can have both Mending and Infinity in Bedrock Edition.
+                EnchantmentKind::Mending => self.java_incompatible_with().remove(EnchantmentKind::Infinity),
+                EnchantmentKind::Infinity => self.java_incompatible_with().remove(EnchantmentKind::Mending),
+                _ => self.java_incompatible_with(),
+            },
+        }
+    }
+
+    fn java_applicable_to(self) -> ItemMask {
         match self {
             EnchantmentKind::Mending => ItemMask::from_slice(&[
                 ItemKind::Book,
@@ -286,7 +342,7 @@ impl EnchantmentKind {
             ]),
             EnchantmentKind::SweepingEdge => ItemMask::from_slice(&[
                 ItemKind::Book,
-                ItemKind::Axe,
+                ItemKind::Sword,
             ]),
             EnchantmentKind::Sharpness => ItemMask::from_slice(&[
                 ItemKind::Book,
@@ -392,7 +448,7 @@ impl EnchantmentKind {
         }
     }
 
-    pub fn incompatible_with(self) -> EnchantmentMask {
+    fn java_incompatible_with(self) -> EnchantmentMask {
         match self {
             EnchantmentKind::Mending => EnchantmentMask::from_slice(&[
                 EnchantmentKind::Infinity
@@ -509,6 +565,8 @@ impl EnchantmentKind {
 
     pub const LAST: Self = Self::QuickCharge;
 
+    pub const COUNT: usize = Self::LAST as usize + 1;
+
     #[inline]
     pub fn book_multiplier(self) -> u8 {
         let i = self.item_multiplier();
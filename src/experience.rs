@@ -0,0 +1,202 @@
+use crate::anvil::Branch;
+
+/// The most steps [`Branch::ordered_by_experience`] puts in order, as it tries every set of steps
+/// that can be done first.
+pub const MAX_ORDERED_STEPS: usize = 20;
+
+/// The experience points needed to reach `level` from level 0, as in Java Edition and Bedrock Edition.
+pub const fn points_for_level(level: u16) -> u64 {
+    let level = level as u64;
+
+    if level <= 16 {
+        level * level + 6 * level
+    } else if level <= 31 {
+        (5 * level * level + 720 - 81 * level) / 2
+    } else {
+        (9 * level * level + 4440 - 325 * level) / 2
+    }
+}
+
+/// The level reached with `points` experience points starting from level 0.
+pub const fn level_for_points(points: u64) -> u16 {
+    let mut low = 0_u16;
+    let mut high = u16::MAX;
+
+    while low < high {
+        let middle = low + (high - low).div_ceil(2);
+
+        if points_for_level(middle) <= points {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    low
+}
+
+/// The points a step that costs `cost` levels takes from a player at `level`, and the level
+/// afterwards. A player without enough levels first collects just enough experience for the step.
+pub const fn spend_levels(level: u16, cost: u16) -> (u64, u16) {
+    if level >= cost {
+        (points_for_level(level) - points_for_level(level - cost), level - cost)
+    } else {
+        (points_for_level(cost), 0)
+    }
+}
+
+/// The fewest points any plan that costs `levels` levels in total can take from a player at
+/// `start_level`.
+///
+/// Up to the levels the player has, the levels taken are the highest ones. Every level beyond that
+/// is taken from a level above 0, which is worth at least 7 points.
+pub(crate) fn least_points(start_level: u16, levels: u32) -> u64 {
+    if levels <= start_level as u32 {
+        points_for_level(start_level) - points_for_level(start_level - levels as u16)
+    } else {
+        points_for_level(start_level) + 7 * (levels - start_level as u32) as u64
+    }
+}
+
+impl Branch {
+    /// The points the steps take from a player at `start_level`, done in order.
+    pub fn experience_points(&self, start_level: u16) -> u64 {
+        self.steps
+            .iter()
+            .fold((0, start_level), |(points, level), step| {
+                let (spent, level) = spend_levels(level, step.cost);
+                (points + spent, level)
+            })
+            .0
+    }
+
+    /// Reorders the steps so that they take the fewest points from a player at `start_level`. A step
+    /// is only moved after the steps that make the items it works on.
+    ///
+    /// Steps that cost the same either way keep their order. Plans with more than
+    /// [`MAX_ORDERED_STEPS`] steps are returned as they are.
+    pub fn ordered_by_experience(mut self, start_level: u16) -> Self {
+        let count = self.steps.len();
+
+        if count > MAX_ORDERED_STEPS {
+            return self;
+        }
+
+        // A step depends on the last unused step before it that made one of its items.
+        let mut used = vec![false; count];
+        let mut dependencies = vec![0_u32; count];
+
+        for (index, step) in self.steps.iter().enumerate() {
+            for item in [Some(step.target), step.sacrifice].into_iter().flatten() {
+                if let Some(source) = (0..index).rev().find(|&source| !used[source] && self.steps[source].result == item) {
+                    used[source] = true;
+                    dependencies[index] |= 1 << source;
+                }
+            }
+        }
+
+        // Which steps were done decides the level, as a player who ran out of levels has none left.
+        let level_after = |done: usize| {
+            let levels = (0..count)
+                .filter(|&index| done & (1 << index) != 0)
+                .map(|index| self.steps[index].cost as u32)
+                .sum::<u32>();
+
+            (start_level as u32).saturating_sub(levels) as u16
+        };
+
+        // The least points to do the remaining steps, and which step to do next.
+        let mut remaining = vec![(0_u64, 0_u8); 1 << count];
+
+        for done in (0..(1_usize << count) - 1).rev() {
+            let level = level_after(done);
+
+            remaining[done] = (0..count)
+                .filter(|&index| done & (1 << index) == 0 && dependencies[index] as usize & !done == 0)
+                .map(|index| (spend_levels(level, self.steps[index].cost).0 + remaining[done | 1 << index].0, index as u8))
+                .min_by_key(|&(points, _)| points)
+                .expect("some step has all of its dependencies done");
+        }
+
+        let mut steps = Vec::with_capacity(count);
+        let mut done = 0_usize;
+
+        while done != (1 << count) - 1 {
+            let index = remaining[done].1 as usize;
+            steps.push(self.steps[index].clone());
+            done |= 1 << index;
+        }
+
+        self.steps = steps;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::anvil::{Branch, Item, Step};
+    use crate::anvil::tests::{book, item};
+    use crate::enchantments::EnchantmentKind;
+    use crate::experience::{level_for_points, points_for_level, spend_levels};
+    use crate::items::ItemKind;
+
+    #[test]
+    fn points() {
+        assert_eq!(points_for_level(0), 0);
+        assert_eq!(points_for_level(5), 55);
+        assert_eq!(points_for_level(16), 352);
+        assert_eq!(points_for_level(17), 394);
+        assert_eq!(points_for_level(30), 1395);
+        assert_eq!(points_for_level(31), 1507);
+        assert_eq!(points_for_level(32), 1628);
+
+        for level in [0, 1, 15, 16, 17, 30, 31, 32, 100, u16::MAX] {
+            assert_eq!(level_for_points(points_for_level(level)), level);
+
+            if level > 0 {
+                assert_eq!(level_for_points(points_for_level(level) - 1), level - 1);
+            }
+        }
+
+        assert_eq!(spend_levels(30, 30), (1395, 0));
+        assert_eq!(spend_levels(35, 30), (2045 - 55, 5));
+        assert_eq!(spend_levels(5, 30), (1395, 0));
+    }
+
+    #[test]
+    fn expensive_steps_first() {
+        let step = |target: Item, sacrifice: Item, result: Item, cost: u16| Step {
+            target,
+            sacrifice: Some(sacrifice),
+            result,
+            cost,
+            renamed: false,
+        };
+
+        let sword = item(ItemKind::Sword, &[], 0);
+        let sharpness = book(EnchantmentKind::Sharpness, 5);
+        let looting = book(EnchantmentKind::Looting, 3);
+        let unbreaking = book(EnchantmentKind::Unbreaking, 3);
+        let first = item(ItemKind::Sword, &[(EnchantmentKind::Sharpness, 5)], 1);
+        let second = item(ItemKind::Book, &[(EnchantmentKind::Looting, 3), (EnchantmentKind::Unbreaking, 3)], 1);
+        let last = item(ItemKind::Sword, &[(EnchantmentKind::Sharpness, 5), (EnchantmentKind::Looting, 3), (EnchantmentKind::Unbreaking, 3)], 3);
+
+        let branch = Branch {
+            steps: vec![
+                step(sword, sharpness, first, 15),
+                step(looting, unbreaking, second, 20),
+                step(first, second, last, 5),
+            ],
+            total_cost: 40,
+        };
+
+        assert_eq!(branch.experience_points(30), 1395 - 315 + 550 + 55);
+
+        let ordered = branch.clone().ordered_by_experience(30);
+        assert_eq!(ordered.steps, vec![branch.steps[1].clone(), branch.steps[0].clone(), branch.steps[2].clone()]);
+        assert_eq!(ordered.experience_points(30), 1395 - 160 + 315 + 55);
+
+        // Without levels to start with, every step takes the same points in any order.
+        assert_eq!(branch.clone().ordered_by_experience(0), branch);
+    }
+}
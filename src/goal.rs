@@ -0,0 +1,606 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use crate::anvil::{keep_relevant_error, Branch, CombinationError, Item, SearchOptions};
+use crate::enchantments::Enchantment;
+use crate::items::ItemKind;
+use crate::rules::Rules;
+use crate::search::best_branch_where;
+
+/// The most items of a pool that can take part in a plan for a target, besides the item to start
+/// from. Every selection of them may be planned.
+pub const MAX_GOAL_CANDIDATES: usize = 16;
+
+/// An enchantment the target must have, at least at its level, or any of its alternatives.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Requirement {
+    pub alternatives: Vec<Enchantment>,
+}
+
+impl Requirement {
+    pub fn new(enchantment: Enchantment) -> Self {
+        Self {
+            alternatives: vec![enchantment],
+        }
+    }
+
+    /// Also accepts `enchantment` instead.
+    pub fn or(mut self, enchantment: Enchantment) -> Self {
+        self.alternatives.push(enchantment);
+        self
+    }
+
+    pub fn is_met_by(&self, item: Item) -> bool {
+        self.alternatives
+            .iter()
+            .any(|alternative| item.enchantments.get_level(alternative.kind()) >= alternative.level())
+    }
+
+    /// Whether the items have enough of an alternative to reach its level, counting two copies of a
+    /// level as one of the next, and it can be applied to the target.
+    fn could_be_met_by(&self, items: impl Iterator<Item = Item> + Clone, kind: ItemKind, rules: Rules) -> bool {
+        self.alternatives.iter().any(|alternative| {
+            let copies = |level: u8| 1_u64.checked_shl(level as u32).unwrap_or(u64::MAX);
+
+            alternative.kind().applicable_to(rules.edition).has(kind)
+                && items
+                    .clone()
+                    .map(|item| item.enchantments.get_level(alternative.kind()))
+                    .filter(|&level| level > 0)
+                    .fold(0_u64, |sum, level| sum.saturating_add(copies(level)))
+                    >= copies(alternative.level())
+        })
+    }
+}
+
+impl Display for Requirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, alternative) in self.alternatives.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" or ")?;
+            }
+
+            write!(f, "{alternative}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The item to make: an item of a kind that meets every requirement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Target {
+    pub kind: ItemKind,
+    pub requirements: Vec<Requirement>,
+}
+
+impl Target {
+    pub fn is_met_by(&self, item: Item) -> bool {
+        item.kind == self.kind && self.requirements.iter().all(|requirement| requirement.is_met_by(item))
+    }
+
+    fn could_be_met_by(&self, items: impl Iterator<Item = Item> + Clone, rules: Rules) -> bool {
+        self.requirements.iter().all(|requirement| requirement.could_be_met_by(items.clone(), self.kind, rules))
+    }
+
+    /// Whether the item has an enchantment that any of the requirements ask for.
+    fn wants(&self, item: Item) -> bool {
+        self.requirements
+            .iter()
+            .flat_map(|requirement| &requirement.alternatives)
+            .any(|alternative| item.enchantments.has(alternative.kind()))
+    }
+}
+
+/// A plan that makes the target from some of the items of a pool.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GoalBranch {
+    pub branch: Branch,
+    /// The positions in the pool of the items the plan merges, starting with one of the target's
+    /// kind.
+    pub used: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoalError {
+    /// The pool has no item of the target's kind to start from.
+    NoBaseItem {
+        kind: ItemKind,
+    },
+    /// None of the items of the pool can give the target these requirements: they have none of the
+    /// alternatives at a high enough level or in enough copies, none that can be applied, or every
+    /// plan loses or fails to raise them.
+    Unreachable {
+        requirements: Vec<Requirement>,
+    },
+    /// Every selection of items that could have met the target failed to merge or lost a required
+    /// enchantment, and this is why.
+    Combination(CombinationError),
+    /// Every target can be made on its own, but the pool does not have the items to make all of
+    /// them at once.
+    NotEnoughItems,
+}
+
+impl Display for GoalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoalError::NoBaseItem { kind } => write!(f, "There is no {kind:?} to start from"),
+            GoalError::Unreachable { requirements } => {
+                f.write_str("The items cannot provide")?;
+
+                for (index, requirement) in requirements.iter().enumerate() {
+                    write!(f, "{} {requirement}", if index > 0 { "," } else { "" })?;
+                }
+
+                Ok(())
+            }
+            GoalError::Combination(error) => write!(f, "{error}"),
+            GoalError::NotEnoughItems => write!(f, "The items are not enough to make every target at once"),
+        }
+    }
+}
+
+impl Error for GoalError {}
+
+impl From<CombinationError> for GoalError {
+    fn from(error: CombinationError) -> Self {
+        GoalError::Combination(error)
+    }
+}
+
+/// Finds the cheapest plan that makes the target from the items of the pool, choosing which ones
+/// to use. Every selection of items that could meet the target is merged with
+/// [`best_branch_where`], so lower books can be merged up to a required level.
+///
+/// Of several plans with the same cost, the first one found is returned, and every selection is
+/// planned before those that add items to it.
+pub fn goal_branch(target: &Target, pool: &[Item], rules: Rules, options: SearchOptions) -> Result<GoalBranch, GoalError> {
+    Ok(selection_branches(target, pool, rules, options)?.swap_remove(0))
+}
+
+/// The cheapest plan of every selection of items that can meet the target, ordered by cost and
+/// then in the order they were found. Of equal items, only the first ones are selected.
+fn selection_branches(target: &Target, pool: &[Item], rules: Rules, options: SearchOptions) -> Result<Vec<GoalBranch>, GoalError> {
+    // Equal items lead to the same plans, so only the first of them is tried.
+    let bases = (0..pool.len())
+        .filter(|&index| pool[index].kind == target.kind && !pool[..index].contains(&pool[index]))
+        .collect::<Vec<_>>();
+
+    if bases.is_empty() {
+        return Err(GoalError::NoBaseItem { kind: target.kind });
+    }
+
+    let unreachable = target
+        .requirements
+        .iter()
+        .filter(|requirement| !requirement.could_be_met_by(pool.iter().copied(), target.kind, rules))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !unreachable.is_empty() {
+        return Err(GoalError::Unreachable { requirements: unreachable });
+    }
+
+    let mut branches = Vec::<GoalBranch>::new();
+    let mut error = None;
+    let mut missed = Vec::new();
+    // The selections of every base so far, each as the first positions of its items' equals.
+    let mut planned = HashSet::<Vec<usize>>::new();
+
+    for base in bases {
+        let candidates = (0..pool.len())
+            .filter(|&index| index != base && target.wants(pool[index]))
+            .collect::<Vec<_>>();
+
+        if candidates.len() > MAX_GOAL_CANDIDATES {
+            return Err(CombinationError::TooManyItems {
+                count: candidates.len(),
+                limit: MAX_GOAL_CANDIDATES,
+            }.into());
+        }
+
+        let items_of = |selection: u32| {
+            std::iter::once(pool[base]).chain((0..candidates.len()).filter(move |&bit| selection & (1 << bit) != 0).map(|bit| pool[candidates[bit]]))
+        };
+
+        // Subsets have lower bits than their supersets, so no item is added to a plan of the same
+        // cost without it.
+        for selection in 0..1_u32 << candidates.len() {
+            // Of equal items, only the first ones are selected.
+            let first_of_equal = (0..candidates.len()).all(|bit| {
+                selection & (1 << bit) == 0 || (0..bit).all(|other| selection & (1 << other) != 0 || pool[candidates[other]] != pool[candidates[bit]])
+            });
+
+            if !first_of_equal || !target.could_be_met_by(items_of(selection), rules) {
+                continue;
+            }
+
+            let used = std::iter::once(base)
+                .chain((0..candidates.len()).filter(|&bit| selection & (1 << bit) != 0).map(|bit| candidates[bit]))
+                .collect::<Vec<_>>();
+
+            // A base can be a candidate of another base, like books for a book, which makes the
+            // same items again.
+            let mut firsts = used.iter().map(|&index| pool.iter().position(|&other| other == pool[index]).unwrap()).collect::<Vec<_>>();
+            firsts.sort_unstable();
+
+            if !planned.insert(firsts) {
+                continue;
+            }
+
+            let items = items_of(selection).collect::<Vec<_>>();
+
+            let branch = match best_branch_where(&items, rules, options, |result| target.is_met_by(result)) {
+                Ok(Some(branch)) => branch,
+                Ok(None) => {
+                    // Every plan lost or failed to raise one of the requirements no item meets alone.
+                    for requirement in &target.requirements {
+                        if !items.iter().any(|&item| requirement.is_met_by(item)) && !missed.contains(requirement) {
+                            missed.push(requirement.clone());
+                        }
+                    }
+
+                    continue;
+                }
+                Err(new_error) => {
+                    keep_relevant_error(&mut error, new_error);
+                    continue;
+                }
+            };
+
+            let index = branches.partition_point(|other| other.branch.total_cost <= branch.total_cost);
+            branches.insert(index, GoalBranch { branch, used });
+        }
+    }
+
+    match error {
+        _ if !branches.is_empty() => Ok(branches),
+        Some(error) => Err(GoalError::Combination(error)),
+        None => Err(GoalError::Unreachable { requirements: missed }),
+    }
+}
+
+/// Finds a plan for every target from one pool, like [`goal_branch`], where every item of the pool
+/// goes into one target at most, with the fewest levels in total. The plans are in the order of
+/// the targets.
+///
+/// If a target cannot be made even on its own, the error of the first such target is returned.
+pub fn set_branches(targets: &[Target], pool: &[Item], rules: Rules, options: SearchOptions) -> Result<Vec<GoalBranch>, GoalError> {
+    let choices = targets
+        .iter()
+        .map(|target| selection_branches(target, pool, rules, options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The least cost of the targets from each one on, if each could have its cheapest plan.
+    let mut least_rest = vec![0; targets.len() + 1];
+
+    for index in (0..targets.len()).rev() {
+        least_rest[index] = least_rest[index + 1] + choices[index][0].branch.total_cost as u32;
+    }
+
+    let mut best = None;
+
+    assign(&choices, &least_rest, pool, &mut vec![false; pool.len()], 0, &mut Vec::new(), &mut best);
+    best.map(|(_, branches)| branches).ok_or(GoalError::NotEnoughItems)
+}
+
+/// Tries every plan for the next target that only uses items that are not taken yet, cheapest
+/// first, and keeps the cheapest set of plans for all targets.
+fn assign(
+    choices: &[Vec<GoalBranch>],
+    least_rest: &[u32],
+    pool: &[Item],
+    taken: &mut [bool],
+    cost: u32,
+    chosen: &mut Vec<GoalBranch>,
+    best: &mut Option<(u32, Vec<GoalBranch>)>,
+) {
+    let target = chosen.len();
+
+    if target == choices.len() {
+        if best.as_ref().is_none_or(|&(best_cost, _)| cost < best_cost) {
+            *best = Some((cost, chosen.clone()));
+        }
+
+        return;
+    }
+
+    for choice in &choices[target] {
+        let total_cost = cost + choice.branch.total_cost as u32;
+
+        if best.as_ref().is_some_and(|&(best_cost, _)| total_cost + least_rest[target + 1] >= best_cost) {
+            break;
+        }
+
+        // Equal items are interchangeable, so every item of the plan takes the first free one equal to it.
+        let mut used = Vec::with_capacity(choice.used.len());
+
+        for &index in &choice.used {
+            let Some(free) = (0..pool.len()).find(|&other| !taken[other] && pool[other] == pool[index]) else {
+                break;
+            };
+
+            taken[free] = true;
+            used.push(free);
+        }
+
+        if used.len() == choice.used.len() {
+            chosen.push(GoalBranch {
+                branch: choice.branch.clone(),
+                used: used.clone(),
+            });
+
+            assign(choices, least_rest, pool, taken, total_cost, chosen, best);
+            chosen.pop();
+        }
+
+        for index in used {
+            taken[index] = false;
+        }
+    }
+}
+
+/// Finds the cheapest way to merge books of only the enchantment's kind in the pool into a book
+/// with the enchantment at least at its level, like [`goal_branch`]. Two books of the same level
+/// make one of the next level, so lower books can be merged up and mixed with higher ones.
+///
+/// The positions of the used books are positions in the whole pool.
+pub fn level_up_branch(pool: &[Item], enchantment: Enchantment, rules: Rules, options: SearchOptions) -> Result<GoalBranch, GoalError> {
+    let books = (0..pool.len())
+        .filter(|&index| pool[index].kind == ItemKind::Book && pool[index].enchantments.iter_contained().all(|other| other.kind() == enchantment.kind()))
+        .filter(|&index| pool[index].enchantments.has(enchantment.kind()))
+        .collect::<Vec<_>>();
+
+    let target = Target {
+        kind: ItemKind::Book,
+        requirements: vec![Requirement::new(enchantment)],
+    };
+
+    if books.is_empty() {
+        return Err(GoalError::Unreachable {
+            requirements: target.requirements,
+        });
+    }
+
+    let mut plan = goal_branch(&target, &books.iter().map(|&index| pool[index]).collect::<Vec<_>>(), rules, options)?;
+
+    for index in &mut plan.used {
+        *index = books[*index];
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::anvil::{CombinationError, SearchOptions};
+    use crate::anvil::tests::{book, item};
+    use crate::enchantments::{Enchantment, EnchantmentKind};
+    use crate::goal::{goal_branch, level_up_branch, selection_branches, set_branches, GoalError, Requirement, Target};
+    use crate::items::ItemKind;
+    use crate::rules::{Edition, GameMode, Rules};
+    use crate::search::best_branch;
+
+    fn requirement(kind: EnchantmentKind, level: u8) -> Requirement {
+        Requirement::new(Enchantment::new(kind, level).unwrap())
+    }
+
+    fn boots_target() -> Target {
+        Target {
+            kind: ItemKind::Boots,
+            requirements: vec![
+                requirement(EnchantmentKind::Protection, 4).or(Enchantment::new(EnchantmentKind::FireProtection, 4).unwrap()),
+                requirement(EnchantmentKind::FeatherFalling, 4),
+                requirement(EnchantmentKind::Mending, 1),
+            ],
+        }
+    }
+
+    #[test]
+    fn cheapest_selection() {
+        let pool = [
+            book(EnchantmentKind::Sharpness, 5),
+            item(ItemKind::Book, &[(EnchantmentKind::FireProtection, 4)], 1),
+            item(ItemKind::Boots, &[], 0),
+            book(EnchantmentKind::FeatherFalling, 3),
+            book(EnchantmentKind::Unbreaking, 3),
+            book(EnchantmentKind::Protection, 4),
+            book(EnchantmentKind::FeatherFalling, 3),
+            book(EnchantmentKind::Mending, 1),
+            book(EnchantmentKind::FeatherFalling, 4),
+        ];
+
+        let plan = goal_branch(&boots_target(), &pool, Rules::default(), SearchOptions::default()).unwrap();
+
+        // The Fire Protection book was worked on before, and one Feather Falling book costs less than two.
+        assert_eq!(plan.used, vec![2, 5, 7, 8]);
+        assert!(boots_target().is_met_by(plan.branch.steps.last().unwrap().result));
+
+        let items = plan.used.iter().map(|&index| pool[index]).collect::<Vec<_>>();
+        assert_eq!(plan.branch, best_branch(&items, Rules::default(), SearchOptions::default()).unwrap());
+
+        // Without the Feather Falling IV book, the two Feather Falling III books are merged first.
+        let plan = goal_branch(&boots_target(), &pool[..8], Rules::default(), SearchOptions::default()).unwrap();
+        assert_eq!(plan.used, vec![2, 3, 5, 6, 7]);
+    }
+
+    #[test]
+    fn larger_selection() {
+        let rules = Rules::new(Edition::Bedrock, GameMode::Survival);
+
+        let target = Target {
+            kind: ItemKind::Sword,
+            requirements: vec![requirement(EnchantmentKind::Sharpness, 5), requirement(EnchantmentKind::Looting, 3)],
+        };
+
+        let loaded_book = item(ItemKind::Book, &[
+            (EnchantmentKind::Sharpness, 5),
+            (EnchantmentKind::Looting, 3),
+            (EnchantmentKind::FireAspect, 2),
+            (EnchantmentKind::Knockback, 2),
+            (EnchantmentKind::Unbreaking, 3),
+            (EnchantmentKind::Mending, 1),
+        ], 15);
+
+        let pool = [item(ItemKind::Sword, &[], 3), loaded_book, book(EnchantmentKind::Sharpness, 5)];
+
+        // The loaded book alone is too expensive to apply. Once the sword has Sharpness V, Bedrock
+        // Edition no longer charges for it, so the extra book makes a plan possible.
+        assert!(matches!(
+            goal_branch(&target, &pool[..2], rules, SearchOptions::default()),
+            Err(GoalError::Combination(CombinationError::TooExpensive { .. }))
+        ));
+
+        let plan = goal_branch(&target, &pool, rules, SearchOptions::default()).unwrap();
+        assert_eq!(plan.used, vec![0, 1, 2]);
+        assert!(target.is_met_by(plan.branch.steps.last().unwrap().result));
+    }
+
+    #[test]
+    fn unreachable() {
+        let pool = [
+            item(ItemKind::Boots, &[], 0),
+            book(EnchantmentKind::FeatherFalling, 3),
+            book(EnchantmentKind::Mending, 1),
+            book(EnchantmentKind::Protection, 3),
+        ];
+
+        assert_eq!(
+            goal_branch(&boots_target(), &pool, Rules::default(), SearchOptions::default()),
+            Err(GoalError::Unreachable {
+                requirements: vec![boots_target().requirements[0].clone(), boots_target().requirements[1].clone()],
+            })
+        );
+
+        assert_eq!(
+            goal_branch(&boots_target(), &pool[1..], Rules::default(), SearchOptions::default()),
+            Err(GoalError::NoBaseItem { kind: ItemKind::Boots })
+        );
+
+        // Sharpness can be on a book, but never on boots.
+        let target = Target {
+            kind: ItemKind::Boots,
+            requirements: vec![requirement(EnchantmentKind::Sharpness, 1)],
+        };
+
+        assert!(matches!(
+            goal_branch(&target, &[pool[0], book(EnchantmentKind::Sharpness, 1)], Rules::default(), SearchOptions::default()),
+            Err(GoalError::Unreachable { .. })
+        ));
+    }
+
+    #[test]
+    fn level_up() {
+        let sharpness = |level| Enchantment::new(EnchantmentKind::Sharpness, level).unwrap();
+
+        let pool = [
+            book(EnchantmentKind::Sharpness, 3),
+            item(ItemKind::Sword, &[(EnchantmentKind::Sharpness, 4)], 0),
+            book(EnchantmentKind::Sharpness, 3),
+            book(EnchantmentKind::Smite, 5),
+            book(EnchantmentKind::Sharpness, 3),
+            item(ItemKind::Book, &[(EnchantmentKind::Sharpness, 3), (EnchantmentKind::Looting, 3)], 0),
+            book(EnchantmentKind::Sharpness, 3),
+            book(EnchantmentKind::Sharpness, 4),
+        ];
+
+        // Two Sharpness III books make one Sharpness IV book, which makes Sharpness V with the other.
+        let plan = level_up_branch(&pool, sharpness(5), Rules::default(), SearchOptions::default()).unwrap();
+        let result = plan.branch.steps.last().unwrap().result;
+
+        assert_eq!(plan.used, vec![0, 2, 7]);
+        assert_eq!(plan.branch.steps.len(), 2);
+        assert_eq!((result.kind, result.enchantments.get_level(EnchantmentKind::Sharpness)), (ItemKind::Book, 5));
+
+        assert_eq!(level_up_branch(&pool, sharpness(3), Rules::default(), SearchOptions::default()).unwrap().used, vec![0]);
+        assert_eq!(level_up_branch(&pool[..5], sharpness(5), Rules::default(), SearchOptions::default()).unwrap_err(), GoalError::Unreachable {
+            requirements: vec![Requirement::new(sharpness(5))],
+        });
+
+        // Without the Sharpness IV book, four Sharpness III books are needed.
+        assert_eq!(level_up_branch(&pool[..7], sharpness(5), Rules::default(), SearchOptions::default()).map(|plan| plan.used.len()), Ok(4));
+
+        // The same books raise the level of the sword as part of its plan.
+        let target = Target {
+            kind: ItemKind::Sword,
+            requirements: vec![requirement(EnchantmentKind::Sharpness, 5)],
+        };
+
+        assert_eq!(goal_branch(&target, &pool, Rules::default(), SearchOptions::default()).unwrap().used, vec![1, 7]);
+    }
+
+    #[test]
+    fn books_selected_once() {
+        let pool = [
+            book(EnchantmentKind::Sharpness, 3),
+            book(EnchantmentKind::Sharpness, 4),
+            book(EnchantmentKind::Sharpness, 3),
+            item(ItemKind::Book, &[(EnchantmentKind::Sharpness, 3), (EnchantmentKind::Looting, 3)], 0),
+            book(EnchantmentKind::Sharpness, 4),
+        ];
+
+        let target = Target {
+            kind: ItemKind::Book,
+            requirements: vec![requirement(EnchantmentKind::Sharpness, 5)],
+        };
+
+        // Every book is a base and a candidate of the others, but each set of books is planned once.
+        let selections = selection_branches(&target, &pool, Rules::default(), SearchOptions::default())
+            .unwrap()
+            .into_iter()
+            .map(|plan| {
+                let mut books = plan.used.iter().map(|&index| pool[index]).collect::<Vec<_>>();
+                books.sort_by_key(|book| (book.enchantments.get_level(EnchantmentKind::Sharpness), book.enchantments.has(EnchantmentKind::Looting)));
+                books
+            })
+            .collect::<Vec<_>>();
+
+        assert!(selections.len() > 1);
+        assert!(selections.iter().enumerate().all(|(index, books)| !selections[..index].contains(books)));
+    }
+
+    #[test]
+    fn shared_pool() {
+        let target = |kind, requirements| Target { kind, requirements };
+        let protection = requirement(EnchantmentKind::Protection, 4);
+
+        let targets = [
+            target(ItemKind::Helmet, vec![
+                protection.clone().or(Enchantment::new(EnchantmentKind::FireProtection, 4).unwrap()),
+                requirement(EnchantmentKind::Unbreaking, 3),
+                requirement(EnchantmentKind::Mending, 1),
+            ]),
+            target(ItemKind::Boots, vec![
+                protection,
+                requirement(EnchantmentKind::Unbreaking, 3),
+                requirement(EnchantmentKind::Mending, 1),
+                requirement(EnchantmentKind::FeatherFalling, 4),
+            ]),
+        ];
+
+        let pool = [
+            item(ItemKind::Boots, &[], 0),
+            book(EnchantmentKind::Mending, 1),
+            item(ItemKind::Helmet, &[], 0),
+            book(EnchantmentKind::Unbreaking, 3),
+            book(EnchantmentKind::Protection, 4),
+            book(EnchantmentKind::Unbreaking, 3),
+            item(ItemKind::Book, &[(EnchantmentKind::FireProtection, 4)], 1),
+            book(EnchantmentKind::FeatherFalling, 4),
+            book(EnchantmentKind::Mending, 1),
+        ];
+
+        // On its own, the helmet would take the cheaper Protection book the boots need.
+        assert_eq!(goal_branch(&targets[0], &pool, Rules::default(), SearchOptions::default()).unwrap().used, vec![2, 1, 3, 4]);
+
+        let plans = set_branches(&targets, &pool, Rules::default(), SearchOptions::default()).unwrap();
+
+        assert_eq!(plans.iter().map(|plan| plan.used.clone()).collect::<Vec<_>>(), vec![vec![2, 1, 3, 6], vec![0, 8, 5, 4, 7]]);
+
+        for (target, plan) in targets.iter().zip(&plans) {
+            assert!(target.is_met_by(plan.branch.steps.last().unwrap().result));
+        }
+
+        assert_eq!(set_branches(&targets, &pool[..8], Rules::default(), SearchOptions::default()), Err(GoalError::NotEnoughItems));
+    }
+}
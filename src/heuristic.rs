@@ -0,0 +1,299 @@
+use std::num::NonZeroUsize;
+use crate::anvil::{keep_relevant_error, Branch, CombinationError, Item, SearchOptions};
+use crate::rules::Rules;
+use crate::search::{check_item_count, lower_bound};
+
+/// The most items the heuristic planners accept.
+pub const MAX_HEURISTIC_ITEMS: usize = u32::BITS as usize;
+
+/// A plan that is not necessarily the cheapest, and how cheap the cheapest plan could be.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeuristicBranch {
+    pub branch: Branch,
+    /// No plan that merges the items costs less than this.
+    pub lower_bound: u32,
+}
+
+impl HeuristicBranch {
+    /// How many levels the plan may cost more than the cheapest one. The plan is the cheapest if
+    /// this is 0.
+    pub fn gap(&self) -> u32 {
+        (self.branch.total_cost as u32).saturating_sub(self.lower_bound)
+    }
+}
+
+/// An item that is left to merge, and the plan it was made with.
+#[derive(Clone)]
+struct Part {
+    item: Item,
+    branch: Branch,
+}
+
+impl Part {
+    /// Merges the items, if the step keeps to the budget for single steps.
+    fn merge(&self, other: &Part, rules: Rules, options: SearchOptions) -> Result<Part, CombinationError> {
+        let step = Branch::of_two(self.item, other.item, rules)?;
+
+        SearchOptions {
+            max_total_cost: None,
+            ..options
+        }.check_budget(&step)?;
+
+        let branch = step.after(&self.branch, &other.branch)?;
+
+        Ok(Part {
+            item: branch.steps.last().unwrap().result,
+            branch,
+        })
+    }
+}
+
+/// The items merged so far by a beam search, and what the plan will cost at least.
+#[derive(Clone)]
+struct State {
+    parts: Vec<Part>,
+    cost: u32,
+    estimate: u32,
+}
+
+impl State {
+    /// The state after merging the parts at `first` and `second`, where the result takes the place
+    /// of the first part.
+    fn merged(&self, first: usize, second: usize, rules: Rules, options: SearchOptions) -> Result<State, CombinationError> {
+        let part = self.parts[first].merge(&self.parts[second], rules, options)?;
+        let cost = self.cost + part.branch.steps.last().unwrap().cost as u32;
+
+        let mut parts = self.parts.clone();
+        parts[first] = part;
+        parts.remove(second);
+
+        let items = parts.iter().map(|part| part.item).collect::<Vec<_>>();
+
+        Ok(State {
+            estimate: cost + lower_bound(&items, rules),
+            parts,
+            cost,
+        })
+    }
+
+    fn has_same_items(&self, other: &State) -> bool {
+        self.parts.iter().map(|part| part.item).eq(other.parts.iter().map(|part| part.item))
+    }
+}
+
+fn parts_of(items: &[Item]) -> Vec<Part> {
+    items
+        .iter()
+        .map(|&item| Part {
+            item,
+            branch: Branch {
+                steps: Vec::new(),
+                total_cost: 0,
+            },
+        })
+        .collect()
+}
+
+/// Checks the item count, and wraps up the plan of a heuristic planner.
+fn finish(
+    items: &[Item],
+    rules: Rules,
+    options: SearchOptions,
+    plan: impl FnOnce() -> Result<Branch, CombinationError>,
+) -> Result<HeuristicBranch, CombinationError> {
+    if let Some(branch) = check_item_count(items, MAX_HEURISTIC_ITEMS)? {
+        return Ok(HeuristicBranch { branch, lower_bound: 0 });
+    }
+
+    let branch = plan()?;
+    let branch = if options.rename { branch.renamed_within(items[0], rules, options.max_step_cost)? } else { branch };
+
+    options.check_budget(&branch)?;
+
+    Ok(HeuristicBranch {
+        branch,
+        lower_bound: lower_bound(items, rules) + options.rename as u32,
+    })
+}
+
+/// Finds a plan quickly by always doing the cheapest step next, for more items than
+/// [`best_branch`](crate::best_branch) can handle.
+///
+/// An error is returned if the steps chosen so far leave items that cannot be merged, or the plan
+/// does not keep to the budget, even if another plan could have.
+pub fn greedy_branch(items: &[Item], rules: Rules, options: SearchOptions) -> Result<HeuristicBranch, CombinationError> {
+    finish(items, rules, options, || {
+        let mut parts = parts_of(items);
+        let mut error = None;
+
+        while parts.len() > 1 {
+            let mut cheapest: Option<(u16, usize, usize, Part)> = None;
+
+            for first in 0..parts.len() {
+                for second in first + 1..parts.len() {
+                    match parts[first].merge(&parts[second], rules, options) {
+                        Ok(part) => {
+                            let cost = part.branch.steps.last().unwrap().cost;
+
+                            if cheapest.as_ref().is_none_or(|&(cheapest_cost, ..)| cost < cheapest_cost) {
+                                cheapest = Some((cost, first, second, part));
+                            }
+                        }
+                        Err(new_error) => keep_relevant_error(&mut error, new_error),
+                    }
+                }
+            }
+
+            let Some((_, first, second, part)) = cheapest else {
+                return Err(error.expect("parts that cannot be merged have an error"));
+            };
+
+            parts[first] = part;
+            parts.remove(second);
+        }
+
+        Ok(parts.pop().unwrap().branch)
+    })
+}
+
+/// Finds a plan by keeping the `width` most promising sets of merged items after every step,
+/// judged by their cost so far and a lower bound on merging what is left. A wider beam finds
+/// cheaper plans, but takes longer.
+///
+/// Like [`greedy_branch`], an error is returned if none of the kept sets can be merged further.
+pub fn beam_branch(items: &[Item], rules: Rules, options: SearchOptions, width: NonZeroUsize) -> Result<HeuristicBranch, CombinationError> {
+    finish(items, rules, options, || {
+        let mut states = vec![State {
+            parts: parts_of(items),
+            cost: 0,
+            estimate: lower_bound(items, rules),
+        }];
+
+        let mut error = None;
+
+        for _ in 1..items.len() {
+            let mut next_states = Vec::new();
+
+            for state in &states {
+                for first in 0..state.parts.len() {
+                    for second in first + 1..state.parts.len() {
+                        match state.merged(first, second, rules, options) {
+                            Ok(next_state) => next_states.push(next_state),
+                            Err(new_error) => keep_relevant_error(&mut error, new_error),
+                        }
+                    }
+                }
+            }
+
+            if next_states.is_empty() {
+                return Err(error.expect("parts that cannot be merged have an error"));
+            }
+
+            // Different orders of the same steps lead to the same items, which are only kept once.
+            next_states.sort_by_key(|state| (state.estimate, state.cost));
+            states.clear();
+
+            for state in next_states {
+                if states.len() == width.get() {
+                    break;
+                }
+
+                if !states.iter().any(|kept: &State| kept.has_same_items(&state)) {
+                    states.push(state);
+                }
+            }
+        }
+
+        let best = states.into_iter().min_by_key(|state| state.cost).unwrap();
+        Ok(best.parts.into_iter().next().unwrap().branch)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use crate::anvil::SearchOptions;
+    use crate::anvil::tests::{book, item};
+    use crate::enchantments::EnchantmentKind;
+    use crate::heuristic::{beam_branch, greedy_branch};
+    use crate::items::ItemKind;
+    use crate::rules::{Edition, GameMode, Rules};
+    use crate::search::best_branch;
+
+    #[test]
+    fn bounded_by_best() {
+        let items = [
+            item(ItemKind::Boots, &[(EnchantmentKind::DepthStrider, 3)], 1),
+            book(EnchantmentKind::Protection, 4),
+            book(EnchantmentKind::FeatherFalling, 4),
+            book(EnchantmentKind::Thorns, 3),
+            book(EnchantmentKind::Unbreaking, 3),
+            book(EnchantmentKind::Mending, 1),
+            book(EnchantmentKind::SoulSpeed, 3),
+        ];
+
+        for options in [SearchOptions::default(), SearchOptions { rename: true, ..SearchOptions::default() }] {
+            let best = best_branch(&items, Rules::default(), options).unwrap();
+
+            for plan in [
+                greedy_branch(&items, Rules::default(), options).unwrap(),
+                beam_branch(&items, Rules::default(), options, NonZeroUsize::new(8).unwrap()).unwrap(),
+            ] {
+                assert_eq!(plan.branch.steps.iter().filter(|step| step.sacrifice.is_some()).count(), items.len() - 1);
+                assert_eq!(plan.branch.total_cost, plan.branch.steps.iter().map(|step| step.cost).sum::<u16>());
+                assert!(plan.lower_bound <= best.total_cost as u32);
+                assert!(plan.branch.total_cost >= best.total_cost);
+                assert_eq!(plan.gap(), (plan.branch.total_cost as u32) - plan.lower_bound);
+            }
+        }
+    }
+
+    #[test]
+    fn many_books() {
+        let rules = Rules::new(Edition::Java, GameMode::Creative);
+
+        // Books of every enchantment that does not conflict with another, as well as two copies.
+        let items = [
+            EnchantmentKind::Mending,
+            EnchantmentKind::Unbreaking,
+            EnchantmentKind::CurseOfBinding,
+            EnchantmentKind::CurseOfVanishing,
+            EnchantmentKind::Protection,
+            EnchantmentKind::Thorns,
+            EnchantmentKind::Respiration,
+            EnchantmentKind::AquaAffinity,
+            EnchantmentKind::SwiftSneak,
+            EnchantmentKind::DepthStrider,
+            EnchantmentKind::FeatherFalling,
+            EnchantmentKind::SoulSpeed,
+            EnchantmentKind::SweepingEdge,
+            EnchantmentKind::Sharpness,
+            EnchantmentKind::Knockback,
+            EnchantmentKind::FireAspect,
+            EnchantmentKind::Looting,
+            EnchantmentKind::Efficiency,
+            EnchantmentKind::Fortune,
+            EnchantmentKind::Power,
+            EnchantmentKind::Punch,
+            EnchantmentKind::Flame,
+            EnchantmentKind::LuckOfTheSea,
+            EnchantmentKind::Lure,
+            EnchantmentKind::Impaling,
+            EnchantmentKind::Loyalty,
+            EnchantmentKind::Channeling,
+            EnchantmentKind::Unbreaking,
+            EnchantmentKind::Protection,
+        ].map(|kind| book(kind, 1));
+
+        let greedy = greedy_branch(&items, rules, SearchOptions::default()).unwrap();
+        let beam = beam_branch(&items, rules, SearchOptions::default(), NonZeroUsize::new(4).unwrap()).unwrap();
+
+        for plan in [&greedy, &beam] {
+            assert_eq!(plan.branch.steps.len(), items.len() - 1);
+            assert!(plan.lower_bound > 0);
+            assert!(plan.branch.total_cost as u32 >= plan.lower_bound);
+        }
+
+        assert_eq!(greedy.lower_bound, beam.lower_bound);
+    }
+}
@@ -32,8 +32,164 @@ pub enum ItemKind {
     CarvedPumpkin,
     Head,
     RecoveryCompass,
+
+    // Repair material
+    Leather,
+    Planks,
+    Cobblestone,
+    IronIngot,
+    GoldIngot,
+    Diamond,
+    NetheriteIngot,
+    TurtleScute,
+    PhantomMembrane,
 }
 
 impl ItemKind {
-    pub const LAST: ItemKind = ItemKind::RecoveryCompass;
+    pub const LAST: ItemKind = ItemKind::PhantomMembrane;
+
+    #[inline]
+    pub const fn is_repair_material(self) -> bool {
+        self as u8 >= ItemKind::Leather as u8
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Durability {
+    pub damage: u16,
+    pub max: u16,
+    pub material: Option<ItemKind>,
+}
+
+impl Durability {
+    /// For items that cannot be damaged, like books.
+    pub const NONE: Self = Self {
+        damage: 0,
+        max: 0,
+        material: None,
+    };
+
+    /// Returns `None` if the damage is larger than the maximum durability.
+    #[inline]
+    pub const fn new(damage: u16, max: u16, material: Option<ItemKind>) -> Option<Self> {
+        if damage > max {
+            None
+        } else {
+            Some(Self { damage, max, material })
+        }
+    }
+
+    #[inline]
+    pub const fn remaining(self) -> u16 {
+        self.max.saturating_sub(self.damage)
+    }
+}
+
+/// How often an item has been worked on in an anvil. Each use doubles the prior work penalty and
+/// adds one, so the penalty is always `2^uses - 1`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct PriorWork(u8);
+
+impl PriorWork {
+    pub const NONE: Self = Self(0);
+
+    /// The number of uses at which the penalty no longer fits into a `u16`.
+    pub const MAX_USES: u8 = 16;
+
+    #[inline]
+    pub const fn from_uses(uses: u8) -> Option<Self> {
+        if uses > Self::MAX_USES {
+            None
+        } else {
+            Some(Self(uses))
+        }
+    }
+
+    /// Returns `None` if no number of anvil uses results in this penalty.
+    #[inline]
+    pub const fn from_penalty(penalty: u16) -> Option<Self> {
+        let uses_power = penalty as u32 + 1;
+
+        if uses_power.is_power_of_two() {
+            Some(Self(uses_power.trailing_zeros() as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Converts the `RepairCost` value stored on items in game.
+    #[inline]
+    pub const fn from_repair_cost(repair_cost: i32) -> Option<Self> {
+        if repair_cost < 0 || repair_cost > u16::MAX as i32 {
+            None
+        } else {
+            Self::from_penalty(repair_cost as u16)
+        }
+    }
+
+    #[inline]
+    pub const fn uses(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn penalty(self) -> u16 {
+        ((1_u32 << self.0) - 1) as u16
+    }
+
+    /// The prior work after one more anvil use, or `None` if its penalty would not fit into a `u16`.
+    #[inline]
+    pub const fn next(self) -> Option<Self> {
+        Self::from_uses(self.0 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod durability {
+        use crate::items::{Durability, ItemKind};
+
+        #[test]
+        fn new() {
+            assert_eq!(Durability::new(100, 429, Some(ItemKind::Diamond)).map(Durability::remaining), Some(329));
+            assert_eq!(Durability::new(429, 429, None).map(Durability::remaining), Some(0));
+            assert_eq!(Durability::new(500, 100, None), None);
+
+            let broken = Durability {
+                damage: 500,
+                max: 100,
+                material: None,
+            };
+
+            assert_eq!(broken.remaining(), 0);
+        }
+    }
+
+    mod prior_work {
+        use crate::items::PriorWork;
+
+        #[test]
+        fn from_penalty() {
+            assert_eq!(PriorWork::from_penalty(0), Some(PriorWork::NONE));
+            assert_eq!(PriorWork::from_penalty(7).map(PriorWork::uses), Some(3));
+            assert_eq!(PriorWork::from_penalty(u16::MAX).map(PriorWork::uses), Some(16));
+            assert_eq!(PriorWork::from_penalty(5), None);
+            assert_eq!(PriorWork::from_penalty(2), None);
+        }
+
+        #[test]
+        fn from_repair_cost() {
+            assert_eq!(PriorWork::from_repair_cost(31).map(PriorWork::uses), Some(5));
+            assert_eq!(PriorWork::from_repair_cost(-1), None);
+            assert_eq!(PriorWork::from_repair_cost(70_000), None);
+        }
+
+        #[test]
+        fn next() {
+            assert_eq!(PriorWork::NONE.next().and_then(PriorWork::next).map(PriorWork::penalty), Some(3));
+            assert_eq!(PriorWork::from_uses(15).unwrap().next().map(PriorWork::penalty), Some(u16::MAX));
+            assert_eq!(PriorWork::from_uses(16).unwrap().next(), None);
+            assert_eq!(PriorWork::from_uses(17), None);
+        }
+    }
 }
\ No newline at end of file
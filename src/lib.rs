@@ -1,14 +1,27 @@
-#![feature(let_chains)]
-#![feature(coroutines)]
-#![feature(coroutine_trait)]
-
 mod dist;
 mod anvil;
+mod anytime;
+mod constraints;
 mod enchantments;
+mod experience;
+mod goal;
+mod heuristic;
 mod bits;
 mod items;
+mod optimal;
+mod rules;
+mod search;
+mod splits;
 
 pub use anvil::*;
+pub use anytime::*;
+pub use constraints::*;
 pub use enchantments::*;
+pub use experience::*;
+pub use goal::*;
+pub use heuristic::*;
 pub use bits::*;
-pub use items::*;
\ No newline at end of file
+pub use items::*;
+pub use optimal::*;
+pub use rules::*;
+pub use search::*;
@@ -0,0 +1,735 @@
+use std::ops::Range;
+use crate::anvil::{keep_relevant_error, Branch, CombinationError, Item, SearchOptions, Step};
+use crate::bits::{EnchantmentMask, ItemMask};
+use crate::items::{ItemKind, PriorWork};
+use crate::rules::Rules;
+use crate::search::check_item_count;
+
+/// The most items [`optimal_branch`] accepts. Time and memory grow exponentially with the item count.
+pub const MAX_OPTIMAL_ITEMS: usize = 20;
+
+/// How the cheapest entry for a state and number of anvil uses was reached.
+#[derive(Copy, Clone, Default)]
+struct Origin {
+    /// The states that were the target and the sacrifice of the last step.
+    target: u32,
+    sacrifice: u32,
+    target_uses: u8,
+    sacrifice_uses: u8,
+}
+
+/// What is needed to tell the cost of merging two subsets without calling [`Item::merge`], in the
+/// common case of compatible enchantments that are only on one side.
+#[derive(Copy, Clone)]
+pub(crate) struct Summary {
+    enchantments: EnchantmentMask,
+    conflicts: EnchantmentMask,
+    applicable_to: ItemMask,
+    pub(crate) book_cost: u32,
+    item_cost: u32,
+    kind: ItemKind,
+    material: Option<ItemKind>,
+    /// Whether the summary can be used, which is not the case for damaged items and repair materials.
+    simple: bool,
+}
+
+impl Summary {
+    pub(crate) fn new(item: Item, rules: Rules) -> Self {
+        let mut summary = Summary {
+            enchantments: EnchantmentMask::empty(),
+            conflicts: EnchantmentMask::empty(),
+            applicable_to: ItemMask::all(),
+            book_cost: 0,
+            item_cost: 0,
+            kind: item.kind,
+            material: item.durability.material,
+            simple: item.durability.damage == 0 && !item.kind.is_repair_material(),
+        };
+
+        for enchantment in item.enchantments.iter_contained() {
+            let kind = enchantment.kind();
+
+            summary.enchantments = summary.enchantments.add(kind);
+            summary.conflicts = summary.conflicts.union(kind.incompatible_with(rules.edition));
+            summary.applicable_to = summary.applicable_to.intersection(kind.applicable_to(rules.edition));
+            summary.book_cost += kind.book_multiplier() as u32 * enchantment.level() as u32;
+            summary.item_cost += kind.item_multiplier() as u32 * enchantment.level() as u32;
+        }
+
+        summary
+    }
+
+    /// Whether merging the two subsets, in either order, keeps every enchantment as it is.
+    #[inline]
+    fn compatible(first: &Summary, second: &Summary) -> bool {
+        !first.enchantments.intersects(second.enchantments) && Summary::keep_each_other(first, second)
+    }
+
+    /// Whether merging the two subsets, in either order, keeps every enchantment, though the ones
+    /// on both sides can change level.
+    #[inline]
+    fn keep_each_other(first: &Summary, second: &Summary) -> bool {
+        first.simple
+            && second.simple
+            && !first.conflicts.union(second.conflicts).intersects(first.enchantments.union(second.enchantments))
+    }
+
+    /// The summary of the item made from both subsets, if they are compatible and of the same kind.
+    pub(crate) fn union(&self, other: &Summary) -> Option<Summary> {
+        if self.enchantments.intersects(other.enchantments) {
+            return None;
+        }
+
+        self.union_sharing(other).map(|summary| Summary {
+            book_cost: self.book_cost + other.book_cost,
+            item_cost: self.item_cost + other.item_cost,
+            ..summary
+        })
+    }
+
+    /// Like [`union`](Self::union), but the subsets may share enchantments. Their costs are left
+    /// out, as they depend on the levels that the shared enchantments end up with.
+    pub(crate) fn union_sharing(&self, other: &Summary) -> Option<Summary> {
+        let kind = match (self.kind, other.kind) {
+            (ItemKind::Book, kind) | (kind, ItemKind::Book) => kind,
+            (kind, other_kind) if kind == other_kind && self.material == other.material => kind,
+            _ => return None,
+        };
+
+        Summary::keep_each_other(self, other).then(|| Summary {
+            enchantments: self.enchantments.union(other.enchantments),
+            conflicts: self.conflicts.union(other.conflicts),
+            applicable_to: self.applicable_to.intersection(other.applicable_to),
+            book_cost: 0,
+            item_cost: 0,
+            kind,
+            material: if kind == self.kind { self.material } else { other.material },
+            simple: true,
+        })
+    }
+
+    /// Whether every enchantment is kept in any merge of the summarized items, so each step costs
+    /// at least the book cost of its sacrifice.
+    pub(crate) fn keeps_enchantments(&self) -> bool {
+        self.simple && self.applicable_to.has(self.kind)
+    }
+
+    /// The cost of merging compatible subsets without prior work, if it only depends on the sacrifice.
+    #[inline]
+    fn cost(target: &Summary, sacrifice: &Summary) -> Option<u32> {
+        if sacrifice.enchantments == EnchantmentMask::empty() || !sacrifice.applicable_to.has(target.kind) {
+            None
+        } else if sacrifice.kind == ItemKind::Book {
+            Some(sacrifice.book_cost)
+        } else if sacrifice.kind == target.kind && sacrifice.material == target.material {
+            Some(sacrifice.item_cost)
+        } else {
+            None
+        }
+    }
+}
+
+/// An item that merging a subset of the items can end in, without its prior work.
+#[derive(Copy, Clone)]
+struct Variant {
+    set: u32,
+    result: Item,
+}
+
+/// The cheapest way to get every subset of the items to every result and number of anvil uses.
+///
+/// Merging a subset in another order can give another item if there are conflicting enchantments,
+/// copies of the same enchantment or damaged items, so every result is kept apart as a variant of
+/// the subset. If renaming, every variant has two states, the second for a renamed item. An item
+/// is renamed on its own before any work, or in a step it is the target of.
+struct Table<'a> {
+    items: &'a [Item],
+    rules: Rules,
+    options: SearchOptions,
+    stride: usize,
+    /// The number of states of every variant.
+    layers: usize,
+    /// The variants of each subset are at `first_variants[set]..first_variants[set + 1]`.
+    first_variants: Vec<u32>,
+    variants: Vec<Variant>,
+    summaries: Vec<Summary>,
+    /// Whether every step keeps all enchantments of the subset, so it has at most one variant.
+    keeps_enchantments: Vec<bool>,
+    /// For each state, a bit for every number of anvil uses that can be reached.
+    uses: Vec<u32>,
+    costs: Vec<u32>,
+    origins: Vec<Origin>,
+}
+
+impl<'a> Table<'a> {
+    fn new(items: &'a [Item], rules: Rules, options: SearchOptions) -> Self {
+        // In survival, items whose penalty alone reaches the limit can only be the final result.
+        let stride = match rules.game_mode.cost_limit() {
+            Some(limit) => (1..=PriorWork::MAX_USES)
+                .find(|&uses| PriorWork::from_uses(uses).unwrap().penalty() >= limit)
+                .unwrap_or(PriorWork::MAX_USES) as usize + 1,
+            None => PriorWork::MAX_USES as usize + 1,
+        }.max(items.iter().map(|item| item.prior_work.uses() as usize + 1).max().unwrap_or(0));
+
+        let layers = 1 + options.rename as usize;
+
+        Self {
+            items,
+            rules,
+            options,
+            stride,
+            layers,
+            first_variants: vec![0; (1 << items.len()) + 1],
+            variants: Vec::with_capacity(1 << items.len()),
+            summaries: Vec::with_capacity(1 << items.len()),
+            keeps_enchantments: vec![false; 1 << items.len()],
+            uses: Vec::with_capacity(layers << items.len()),
+            costs: Vec::with_capacity((layers * stride) << items.len()),
+            origins: Vec::with_capacity((layers * stride) << items.len()),
+        }
+    }
+
+    /// Solves every subset and returns the most relevant error on the way, or `None` if `stop`
+    /// returned true before the end. It is asked once per subset.
+    fn solve(&mut self, stop: &dyn Fn() -> bool) -> Option<Option<CombinationError>> {
+        let mut error = None;
+
+        for set in 1..1_u32 << self.items.len() {
+            if stop() {
+                return None;
+            }
+
+            if set.count_ones() == 1 {
+                self.leaf(set);
+            } else {
+                // Only visit the splits where the lowest item is in the first part, split tries both orders.
+                let lowest = set & set.wrapping_neg();
+                let rest = set ^ lowest;
+                let mut subset = rest;
+
+                self.keeps_enchantments[set as usize] = self.keeps_enchantments[rest as usize] && !self.variants_of(rest).is_empty() && {
+                    let summary = |set: u32| &self.summaries[self.first_variants[set as usize] as usize];
+                    summary(lowest).union(summary(rest)).is_some_and(|summary| summary.keeps_enchantments())
+                };
+
+                loop {
+                    subset = subset.wrapping_sub(1) & rest;
+
+                    let part = lowest | subset;
+                    self.split(set, part, set ^ part, &mut error);
+
+                    if subset == 0 {
+                        break;
+                    }
+                }
+            }
+
+            for state in self.first_variants[set as usize] as usize * self.layers..self.variants.len() * self.layers {
+                self.prune(state);
+            }
+
+            self.first_variants[set as usize + 1] = self.variants.len() as u32;
+        }
+
+        Some(error)
+    }
+
+    fn leaf(&mut self, set: u32) {
+        let item = self.items[set.trailing_zeros() as usize];
+        let variant = self.variant_of(set, item);
+        let state = variant * self.layers;
+        let uses = item.prior_work.uses() as usize;
+
+        self.keeps_enchantments[set as usize] = self.summaries[variant].keeps_enchantments();
+
+        self.costs[state * self.stride + uses] = 0;
+
+        // Renaming on its own does not add prior work.
+        if self.layers > 1 {
+            if let Ok(step) = Item::rename(item, self.rules) {
+                if self.options.check_step(&step).is_ok() {
+                    self.costs[(state + 1) * self.stride + uses] = step.cost as u32;
+                }
+            }
+        }
+    }
+
+    /// The variants of a subset that was already solved.
+    fn variants_of(&self, set: u32) -> Range<usize> {
+        self.first_variants[set as usize] as usize..self.first_variants[set as usize + 1] as usize
+    }
+
+    /// The variant of the set with the item as its result, which is added if there is none yet.
+    fn variant_of(&mut self, set: u32, item: Item) -> usize {
+        let result = Item {
+            prior_work: PriorWork::NONE,
+            ..item
+        };
+
+        let first = self.first_variants[set as usize] as usize;
+
+        if let Some(index) = self.variants[first..].iter().position(|variant| variant.result == result) {
+            return first + index;
+        }
+
+        self.variants.push(Variant {
+            set,
+            result,
+        });
+
+        self.summaries.push(Summary::new(result, self.rules));
+        self.uses.resize(self.uses.len() + self.layers, 0);
+        self.costs.resize(self.costs.len() + self.layers * self.stride, u32::MAX);
+        self.origins.resize(self.origins.len() + self.layers * self.stride, Origin::default());
+        self.variants.len() - 1
+    }
+
+    /// Removes the entries with more anvil uses that are not cheaper, since they can never lead to a better plan.
+    fn prune(&mut self, state: usize) {
+        let mut cheapest = u32::MAX;
+
+        for uses in 0..self.stride {
+            let cost = &mut self.costs[state * self.stride + uses];
+
+            if *cost < cheapest {
+                cheapest = *cost;
+                self.uses[state] |= 1 << uses;
+            } else {
+                *cost = u32::MAX;
+            }
+        }
+    }
+
+    /// Tries both orders of merging every variant of the two parts of the set.
+    fn split(&mut self, set: u32, first: u32, second: u32, error: &mut Option<CombinationError>) {
+        for first_variant in self.variants_of(first) {
+            if self.uses[first_variant * self.layers] == 0 {
+                continue;
+            }
+
+            for second_variant in self.variants_of(second) {
+                if self.uses[second_variant * self.layers] == 0 {
+                    continue;
+                }
+
+                let compatible = Summary::compatible(&self.summaries[first_variant], &self.summaries[second_variant]);
+
+                self.step(set, first_variant, second_variant, compatible, error);
+                self.step(set, second_variant, first_variant, compatible, error);
+            }
+        }
+    }
+
+    /// Merges two variants, and records the entries it reaches.
+    #[inline]
+    fn step(&mut self, set: u32, target: usize, sacrifice: usize, compatible: bool, error: &mut Option<CombinationError>) {
+        // Without prior work, the cost of the step is only the cost of the enchantments and repairs.
+        let cost = compatible.then(|| Summary::cost(&self.summaries[target], &self.summaries[sacrifice])).flatten();
+
+        let first = self.first_variants[set as usize] as usize;
+
+        let (variant, step_cost) = match cost {
+            // The one variant of a set that keeps all enchantments is the same for every split.
+            Some(cost) if self.keeps_enchantments[set as usize] && first < self.variants.len() => (first, cost),
+            Some(cost) => {
+                let (target_result, sacrifice_result) = (self.variants[target].result, self.variants[sacrifice].result);
+                let result = Item {
+                    enchantments: target_result.enchantments.union(sacrifice_result.enchantments),
+                    ..target_result
+                };
+
+                (self.variant_of(set, result), cost)
+            }
+            None => match Item::merge(self.variants[target].result, self.variants[sacrifice].result, self.rules) {
+                Ok(step) => (self.variant_of(set, step.result), step.cost as u32),
+                Err(new_error) => {
+                    keep_relevant_error(error, new_error);
+                    return;
+                }
+            },
+        };
+
+        self.relax(variant, target, sacrifice, step_cost, error);
+    }
+
+    /// Records the entries reached by merging the target and sacrifice variants with any of their
+    /// anvil uses. Only the target can be renamed already, the name of the sacrifice is lost.
+    #[inline]
+    fn relax(&mut self, variant: usize, target: usize, sacrifice: usize, step_cost: u32, error: &mut Option<CombinationError>) {
+        let limit = self.rules.game_mode.cost_limit();
+        let budget = self.options.max_step_cost.unwrap_or(u16::MAX) as u32;
+        let sacrifice_state = sacrifice * self.layers;
+
+        for layer in 0..self.layers {
+            let target_state = target * self.layers + layer;
+            let mut target_uses_left = self.uses[target_state];
+
+            while target_uses_left != 0 {
+                let target_uses = target_uses_left.trailing_zeros() as usize;
+                target_uses_left &= target_uses_left - 1;
+
+                let target_cost = self.costs[target_state * self.stride + target_uses];
+                let mut sacrifice_uses_left = self.uses[sacrifice_state];
+
+                while sacrifice_uses_left != 0 {
+                    let sacrifice_uses = sacrifice_uses_left.trailing_zeros() as usize;
+                    sacrifice_uses_left &= sacrifice_uses_left - 1;
+
+                    let cost = step_cost + penalty(target_uses) + penalty(sacrifice_uses);
+                    let uses = target_uses.max(sacrifice_uses) + 1;
+
+                    if cost > u16::MAX as u32 || uses > PriorWork::MAX_USES as usize {
+                        if error.is_none() {
+                            *error = Some(CombinationError::Overflow {
+                                target: self.variants[target].result,
+                                sacrifice: Some(self.variants[sacrifice].result),
+                            });
+                        }
+
+                        continue;
+                    }
+
+                    if limit.is_some_and(|limit| cost >= limit as u32) {
+                        if !matches!(error, Some(CombinationError::TooExpensive { .. })) {
+                            *error = Some(CombinationError::TooExpensive {
+                                target: self.variants[target].result,
+                                sacrifice: Some(self.variants[sacrifice].result),
+                                cost: cost as u16,
+                            });
+                        }
+
+                        continue;
+                    }
+
+                    if cost > budget {
+                        keep_relevant_error(error, CombinationError::StepOverBudget {
+                            target: self.variants[target].result,
+                            sacrifice: Some(self.variants[sacrifice].result),
+                            cost: cost as u16,
+                            limit: budget as u16,
+                        });
+
+                        continue;
+                    }
+
+                    let total_cost = target_cost + self.costs[sacrifice_state * self.stride + sacrifice_uses] + cost;
+                    let origin = || Origin {
+                        target: target_state as u32,
+                        sacrifice: sacrifice_state as u32,
+                        target_uses: target_uses as u8,
+                        sacrifice_uses: sacrifice_uses as u8,
+                    };
+
+                    self.improve(variant * self.layers + layer, uses, total_cost, origin);
+
+                    // Renaming during the step costs one more level.
+                    if layer + 1 < self.layers && cost < budget && limit.is_none_or(|limit| cost + 1 < limit as u32) {
+                        self.improve(variant * self.layers + layer + 1, uses, total_cost + 1, origin);
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn improve(&mut self, state: usize, uses: usize, total_cost: u32, origin: impl FnOnce() -> Origin) {
+        let index = state * self.stride + uses;
+
+        if total_cost < self.costs[index] {
+            self.costs[index] = total_cost;
+            self.origins[index] = origin();
+        }
+    }
+
+    /// The cheapest entry of any variant of the set in the given layer, as its state and uses.
+    fn cheapest(&self, set: u32, layer: usize) -> Option<(usize, usize)> {
+        self.variants_of(set)
+            .flat_map(|variant| (0..self.stride).map(move |uses| (variant * self.layers + layer, uses)))
+            .filter(|&(state, uses)| self.costs[state * self.stride + uses] != u32::MAX)
+            .min_by_key(|&(state, uses)| self.costs[state * self.stride + uses])
+    }
+
+    fn is_renamed(&self, state: usize) -> bool {
+        state % self.layers == 1
+    }
+
+    fn build(&self, state: usize, uses: usize, steps: &mut Vec<Step>) -> Result<Item, CombinationError> {
+        let set = self.variants[state / self.layers].set;
+        let renamed = self.is_renamed(state);
+
+        if set.count_ones() == 1 {
+            let item = self.items[set.trailing_zeros() as usize];
+
+            if renamed {
+                steps.push(Item::rename(item, self.rules)?);
+            }
+
+            return Ok(item);
+        }
+
+        let origin = self.origins[state * self.stride + uses];
+        let target = self.build(origin.target as usize, origin.target_uses as usize, steps)?;
+        let sacrifice = self.build(origin.sacrifice as usize, origin.sacrifice_uses as usize, steps)?;
+
+        let step = if renamed && !self.is_renamed(origin.target as usize) {
+            Item::combine_renamed(target, sacrifice, self.rules)?
+        } else {
+            Item::combine(target, sacrifice, self.rules)?
+        };
+
+        let result = step.result;
+        steps.push(step);
+        Ok(result)
+    }
+
+    /// The cheapest plan for all items, renamed if the table is, or the error if there is none.
+    fn branch(&self, error: Option<CombinationError>) -> Result<Branch, CombinationError> {
+        let full = (1_u32 << self.items.len()) - 1;
+
+        let Some((state, uses)) = self.cheapest(full, self.layers - 1) else {
+            // Renaming the cheapest plan without renaming tells why no plan can be renamed.
+            return match self.cheapest(full, 0) {
+                Some((state, uses)) => self.plan(state, uses)?.renamed_within(self.items[0], self.rules, self.options.max_step_cost),
+                None => Err(error.expect("a set of several items without a plan has an error")),
+            };
+        };
+
+        let branch = self.plan(state, uses)?;
+        self.options.check_budget(&branch)?;
+        Ok(branch)
+    }
+
+    fn plan(&self, state: usize, uses: usize) -> Result<Branch, CombinationError> {
+        let mut steps = Vec::with_capacity(self.items.len());
+        self.build(state, uses, &mut steps)?;
+
+        let total_cost = steps.iter().try_fold(0_u16, |total_cost, step| total_cost.checked_add(step.cost));
+
+        let Some(total_cost) = total_cost else {
+            return Err(CombinationError::Overflow {
+                target: self.items[0],
+                sacrifice: None,
+            });
+        };
+
+        Ok(Branch {
+            total_cost,
+            steps,
+        })
+    }
+}
+
+#[inline]
+fn penalty(uses: usize) -> u32 {
+    (1 << uses) - 1
+}
+
+/// What solving every subset of the items tells the searches.
+pub(crate) struct Subsets {
+    /// The cost of the cheapest plan and the fewest anvil uses for every subset of the items, if
+    /// there is any plan for it within the budget for a single step, not counting renaming.
+    pub(crate) cheapest: Vec<Option<(u32, u8)>>,
+    /// The plan that [`optimal_branch`] finds.
+    pub(crate) branch: Result<Branch, CombinationError>,
+}
+
+/// Solves every subset of the items, unless `stop` returns true before the end.
+pub(crate) fn solve_subsets(items: &[Item], rules: Rules, options: SearchOptions, stop: &dyn Fn() -> bool) -> Option<Subsets> {
+    let mut table = Table::new(items, rules, options);
+    let error = table.solve(stop)?;
+
+    let cheapest = (0..1_u32 << items.len())
+        .map(|set| {
+            let (state, uses) = table.cheapest(set, 0)?;
+            let fewest_uses = table.variants_of(set).map(|variant| table.uses[variant * table.layers].trailing_zeros()).min()?;
+            Some((table.costs[state * table.stride + uses], fewest_uses as u8))
+        })
+        .collect();
+
+    Some(Subsets {
+        cheapest,
+        branch: table.branch(error),
+    })
+}
+
+/// Finds the cheapest plan to merge all items by solving every subset of them once, instead of
+/// enumerating every plan like [`branch_iterator`](crate::branch_iterator).
+///
+/// Every item that a subset can be merged into is solved apart, so the plan is the cheapest of
+/// all plans that keep to the budget, renaming included, even if conflicting enchantments or
+/// copies of an enchantment make the result depend on the order.
+///
+/// Time grows with 3 to the power of the item count, and memory with the number of different
+/// results. This falls short of solving 16 items well under a second: in a release build, 16 books
+/// with different enchantments take about 2 seconds, and 16 books that share enchantments 7 to 13
+/// seconds, as every subset of them has several results and numbers of anvil uses to try.
+pub fn optimal_branch(items: &[Item], rules: Rules, options: SearchOptions) -> Result<Branch, CombinationError> {
+    if let Some(branch) = check_item_count(items, MAX_OPTIMAL_ITEMS)? {
+        return Ok(branch);
+    }
+
+    let mut table = Table::new(items, rules, options);
+    let error = table.solve(&|| false).expect("the table is solved to the end if never stopped");
+    table.branch(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::anvil::{CombinationError, SearchOptions};
+    use crate::anvil::tests::{book, collect, item};
+    use crate::enchantments::EnchantmentKind;
+    use crate::items::ItemKind;
+    use crate::optimal::optimal_branch;
+    use crate::rules::{Edition, GameMode, Rules};
+
+    #[test]
+    fn matches_enumeration() {
+        let items = [
+            item(ItemKind::Boots, &[(EnchantmentKind::DepthStrider, 3)], 1),
+            book(EnchantmentKind::Protection, 4),
+            book(EnchantmentKind::FeatherFalling, 4),
+            book(EnchantmentKind::Thorns, 3),
+            book(EnchantmentKind::Unbreaking, 3),
+            book(EnchantmentKind::Mending, 1),
+            book(EnchantmentKind::SoulSpeed, 3),
+        ];
+
+        for rules in [Rules::default(), Rules::new(Edition::Java, GameMode::Creative)] {
+            let best = collect(&items, rules, SearchOptions::default())
+                .into_iter()
+                .map(|branch| branch.total_cost)
+                .min()
+                .unwrap();
+
+            let branch = optimal_branch(&items, rules, SearchOptions::default()).unwrap();
+
+            assert!(branch.total_cost <= best);
+            assert_eq!(branch.steps.len(), items.len() - 1);
+            assert_eq!(branch.total_cost, branch.steps.iter().map(|step| step.cost).sum::<u16>());
+        }
+    }
+
+    #[test]
+    fn order_dependent_results() {
+        let item_sets = [
+            // Four copies of Sharpness III reach IV or V depending on the order, and Smite conflicts with it.
+            vec![
+                item(ItemKind::Sword, &[], 0),
+                book(EnchantmentKind::Sharpness, 3),
+                book(EnchantmentKind::Sharpness, 3),
+                book(EnchantmentKind::Sharpness, 3),
+                book(EnchantmentKind::Sharpness, 3),
+                book(EnchantmentKind::Looting, 3),
+                book(EnchantmentKind::Smite, 5),
+            ],
+            vec![
+                item(ItemKind::Pickaxe, &[(EnchantmentKind::Efficiency, 3)], 1),
+                book(EnchantmentKind::Efficiency, 4),
+                book(EnchantmentKind::Efficiency, 4),
+                book(EnchantmentKind::Fortune, 3),
+                book(EnchantmentKind::SilkTouch, 1),
+                book(EnchantmentKind::Unbreaking, 2),
+                book(EnchantmentKind::Unbreaking, 2),
+            ],
+        ];
+
+        for items in item_sets {
+            for rules in [Rules::default(), Rules::new(Edition::Java, GameMode::Creative), Rules::new(Edition::Bedrock, GameMode::Survival)] {
+                for options in [SearchOptions::default(), SearchOptions { rename: true, ..SearchOptions::default() }] {
+                    let best = collect(&items, rules, options).into_iter().map(|branch| branch.total_cost).min().unwrap();
+                    let branch = optimal_branch(&items, rules, options).unwrap();
+
+                    assert!(branch.total_cost <= best);
+                    assert_eq!(branch.total_cost, branch.steps.iter().map(|step| step.cost).sum::<u16>());
+                    assert_eq!(branch.steps.iter().filter(|step| step.renamed).count(), options.rename as usize);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn budget() {
+        let items = [
+            item(ItemKind::Boots, &[(EnchantmentKind::DepthStrider, 3)], 1),
+            book(EnchantmentKind::Protection, 4),
+            book(EnchantmentKind::FeatherFalling, 4),
+            book(EnchantmentKind::Thorns, 3),
+            book(EnchantmentKind::Unbreaking, 3),
+            book(EnchantmentKind::Mending, 1),
+        ];
+
+        for rename in [false, true] {
+            let options = SearchOptions { rename, ..SearchOptions::default() };
+            let cheapest = optimal_branch(&items, Rules::default(), options).unwrap();
+            let highest_step_cost = cheapest.steps.iter().map(|step| step.cost).max().unwrap();
+
+            let options = SearchOptions {
+                max_step_cost: Some(highest_step_cost - 1),
+                ..options
+            };
+
+            let cheapest = collect(&items, Rules::default(), options).into_iter().map(|branch| branch.total_cost).min().unwrap();
+            let branch = optimal_branch(&items, Rules::default(), options).unwrap();
+            let expected = branch.total_cost;
+
+            assert!(expected <= cheapest);
+            assert!(branch.steps.iter().all(|step| step.cost < highest_step_cost));
+
+            assert_eq!(
+                optimal_branch(&items, Rules::default(), SearchOptions { max_total_cost: Some(expected - 1), ..options }),
+                Err(CombinationError::TotalOverBudget { limit: expected - 1 })
+            );
+        }
+
+        assert!(matches!(
+            optimal_branch(&items, Rules::default(), SearchOptions { max_step_cost: Some(2), ..SearchOptions::default() }),
+            Err(CombinationError::StepOverBudget { limit: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn costlier_order_first() {
+        // Merging the boots with Frost Walker II into the others costs less, but keeping them as the
+        // target saves more later on.
+        let items = [
+            item(ItemKind::Boots, &[(EnchantmentKind::Unbreaking, 1)], 0),
+            item(ItemKind::Boots, &[(EnchantmentKind::FrostWalker, 2)], 0),
+            item(ItemKind::Book, &[(EnchantmentKind::FrostWalker, 2), (EnchantmentKind::FeatherFalling, 4)], 1),
+            item(ItemKind::Book, &[(EnchantmentKind::Protection, 2), (EnchantmentKind::FrostWalker, 1)], 0),
+            item(ItemKind::Book, &[(EnchantmentKind::FireProtection, 3), (EnchantmentKind::FeatherFalling, 2)], 0),
+        ];
+
+        let cheapest = collect(&items, Rules::default(), SearchOptions::default()).into_iter().map(|branch| branch.total_cost).min().unwrap();
+        let branch = optimal_branch(&items, Rules::default(), SearchOptions::default()).unwrap();
+
+        assert_eq!(branch.total_cost, 27);
+        assert!(branch.total_cost <= cheapest);
+    }
+
+    #[test]
+    fn many_items() {
+        let items = [
+            EnchantmentKind::Mending,
+            EnchantmentKind::Unbreaking,
+            EnchantmentKind::CurseOfBinding,
+            EnchantmentKind::CurseOfVanishing,
+            EnchantmentKind::Protection,
+            EnchantmentKind::Thorns,
+            EnchantmentKind::Respiration,
+            EnchantmentKind::AquaAffinity,
+            EnchantmentKind::SwiftSneak,
+            EnchantmentKind::DepthStrider,
+            EnchantmentKind::FeatherFalling,
+            EnchantmentKind::SoulSpeed,
+            EnchantmentKind::Sharpness,
+            EnchantmentKind::Knockback,
+            EnchantmentKind::FireAspect,
+            EnchantmentKind::Looting,
+        ].map(|kind| book(kind, kind.max_level()));
+
+        let branch = optimal_branch(&items, Rules::new(Edition::Java, GameMode::Creative), SearchOptions::default()).unwrap();
+
+        assert_eq!(branch.steps.len(), 15);
+        assert!(branch.steps.last().unwrap().result.enchantments.size() == 16);
+    }
+}
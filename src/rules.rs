@@ -0,0 +1,37 @@
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum Edition {
+    #[default]
+    Java,
+    Bedrock,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum GameMode {
+    #[default]
+    Survival,
+    Creative,
+}
+
+impl GameMode {
+    /// The cost at which the anvil shows "Too Expensive!" and refuses the operation, if any.
+    #[inline]
+    pub const fn cost_limit(self) -> Option<u16> {
+        match self {
+            GameMode::Survival => Some(40),
+            GameMode::Creative => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Rules {
+    pub edition: Edition,
+    pub game_mode: GameMode,
+}
+
+impl Rules {
+    #[inline]
+    pub const fn new(edition: Edition, game_mode: GameMode) -> Self {
+        Self { edition, game_mode }
+    }
+}
@@ -0,0 +1,1252 @@
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use arrayvec::ArrayVec;
+use crate::anvil::{keep_relevant_error, Branch, CombinationError, Item, SearchOptions};
+use crate::experience::least_points;
+use crate::bits::Enchantments;
+use crate::constraints::{Constraint, ConstraintMasks, TargetSide};
+use crate::optimal::{solve_subsets, Summary, MAX_OPTIMAL_ITEMS};
+use crate::rules::{Edition, Rules};
+use crate::splits::{distinct_splits, positions, DistinctSplits};
+
+/// The most items the searches accept, as they solve every subset of the items first. Time and
+/// memory grow exponentially with the item count.
+pub const MAX_SEARCH_ITEMS: usize = MAX_OPTIMAL_ITEMS;
+
+/// What is known about merging a subset of the items before trying any plan.
+#[derive(Copy, Clone)]
+struct Bound {
+    /// No plan that merges the subset is cheaper than this.
+    cost: u32,
+    /// The merged item has at least this many anvil uses.
+    uses: u8,
+    /// What a step is charged at least for the enchantments of the subset as its sacrifice, set if
+    /// every step of every plan keeps all of them.
+    charged: Option<u32>,
+}
+
+impl Bound {
+    fn new(items: &[Item], set: u32, cheapest: Option<(u32, u8)>, rules: Rules) -> Self {
+        let members = || (0..items.len()).filter(move |&index| set & (1 << index) != 0).map(|index| items[index]);
+        let count = set.count_ones();
+
+        let summary = members()
+            .map(|item| Some(Summary::new(item, rules)))
+            .reduce(|summary, other| summary?.union(&other?))
+            .flatten()
+            .filter(Summary::keeps_enchantments);
+
+        // Java Edition charges for the level an enchantment ends up with, which is at least its
+        // highest level in the subset, even if the target has it too.
+        let charged = match rules.edition {
+            Edition::Java => members()
+                .map(|item| Some(Summary::new(item, rules)))
+                .reduce(|summary, other| summary?.union_sharing(&other?))
+                .flatten()
+                .filter(Summary::keeps_enchantments)
+                .map(|_| book_cost(members().fold(Enchantments::empty(), |levels, item| levels.union(item.enchantments)))),
+            Edition::Bedrock => summary.map(|summary| summary.book_cost),
+        };
+
+        if count <= 1 {
+            return Self {
+                cost: 0,
+                uses: members().map(|item| item.prior_work.uses()).max().unwrap_or(0),
+                charged,
+            };
+        }
+
+        // Every step costs at least one level. If no enchantment is ever dropped, every item but the
+        // final target is part of a sacrifice at least once.
+        let transfer_cost = summary.map_or(0, |summary| {
+            summary.book_cost - members().map(|item| Summary::new(item, rules).book_cost).max().unwrap()
+        });
+
+        // Every item is part of exactly one step, and so is every intermediate item but the last.
+        let penalties = members().map(|item| item.prior_work.penalty() as u32).sum::<u32>() + intermediate_penalties(count);
+
+        let mut bound = Self {
+            cost: transfer_cost.max(count - 1) + penalties,
+            uses: (members().map(|item| item.prior_work.uses()).max().unwrap() + 1).max(fewest_uses(count)),
+            charged,
+        };
+
+        if let Some((cost, uses)) = cheapest {
+            bound.cost = bound.cost.max(cost);
+            bound.uses = bound.uses.max(uses);
+        }
+
+        bound
+    }
+
+    /// The least cost of the step that merges the results of two disjoint subsets into the result
+    /// of `whole`.
+    fn step_cost(whole: &Bound, first: &Bound, second: &Bound) -> u32 {
+        let transfer_cost = match (whole.charged, first.charged, second.charged) {
+            (Some(_), Some(first), Some(second)) => first.min(second),
+            _ => 0,
+        };
+
+        transfer_cost.max(1) + penalty(first.uses) + penalty(second.uses)
+    }
+}
+
+/// The book cost of the enchantments at their levels.
+fn book_cost(enchantments: Enchantments) -> u32 {
+    enchantments.iter_contained().map(|enchantment| enchantment.kind().book_multiplier() as u32 * enchantment.level() as u32).sum()
+}
+
+fn penalty(uses: u8) -> u32 {
+    (1 << uses) - 1
+}
+
+/// The fewest anvil uses of an item merged from `count` items.
+fn fewest_uses(count: u32) -> u8 {
+    count.next_power_of_two().trailing_zeros() as u8
+}
+
+/// The least total penalty of the intermediate items in a plan for `count` items, not counting
+/// the final item.
+fn intermediate_penalties(count: u32) -> u32 {
+    let mut least = [0_u32; u32::BITS as usize + 1];
+
+    for total in 2..=count as usize {
+        least[total] = (1..total)
+            .map(|part| {
+                let penalty_of = |count: usize| if count > 1 { penalty(fewest_uses(count as u32)) } else { 0 };
+                least[part] + least[total - part] + penalty_of(part) + penalty_of(total - part)
+            })
+            .min()
+            .unwrap();
+    }
+
+    least[count as usize]
+}
+
+/// A search over the same plans as [`branch_iterator`](crate::branch_iterator), which skips
+/// every split whose lower bound is not below the limit.
+pub(crate) struct BoundedSearch<'a> {
+    pub(crate) items: &'a [Item],
+    rules: Rules,
+    pub(crate) options: SearchOptions,
+    bounds: Vec<Bound>,
+    /// The plans keep to the constraints, for which the indices of the items are their positions.
+    constraints: Arc<ConstraintMasks>,
+    /// The cheapest plan for all items, found while solving every subset for the bounds.
+    pub(crate) optimal: Result<Branch, CombinationError>,
+    /// How many splits were searched and how many were skipped, at any depth.
+    pub(crate) visited: AtomicU64,
+    pub(crate) pruned: AtomicU64,
+}
+
+impl<'a> BoundedSearch<'a> {
+    pub(crate) fn new(items: &'a [Item], rules: Rules, options: SearchOptions) -> Self {
+        Self::unless_stopped(items, rules, options, &|| false).expect("the subsets are solved to the end if never stopped")
+    }
+
+    /// Like [`new`](Self::new), but gives up solving the subsets for the bounds as soon as `stop`
+    /// returns true.
+    pub(crate) fn unless_stopped(items: &'a [Item], rules: Rules, options: SearchOptions, stop: &dyn Fn() -> bool) -> Option<Self> {
+        let subsets = solve_subsets(items, rules, options, stop)?;
+
+        Some(Self {
+            items,
+            rules,
+            options,
+            bounds: subsets.cheapest
+                .into_iter()
+                .zip(0..)
+                .map(|(cheapest, set)| Bound::new(items, set, cheapest, rules))
+                .collect(),
+            constraints: Arc::default(),
+            optimal: subsets.branch,
+            visited: AtomicU64::new(0),
+            pruned: AtomicU64::new(0),
+        })
+    }
+
+    /// Calls `f` with the plans for the items at `indices` that cost less than `limit` and can be
+    /// part of a plan that meets the goal, in the order of [`branch_iterator`](crate::branch_iterator).
+    /// The limit may shrink during the search.
+    ///
+    /// Like [`branch_iterator`](crate::branch_iterator), an error is only returned if there is no
+    /// plan at all, plans above the limit count as found. The result of the items becomes the
+    /// final result if `on_spine`, which only matters for the constraints.
+    fn visit(&self, indices: &[u8], on_spine: bool, goal: Goal, limit: &dyn Fn() -> u32, f: &mut dyn FnMut(Branch)) -> Result<(), CombinationError> {
+        match *indices {
+            [_] => f(Branch {
+                steps: Vec::new(),
+                total_cost: 0,
+            }),
+            [first, second] => {
+                let is_last = indices.len() == self.items.len();
+
+                let Some(target_side) = self.constraints.target_side(1 << first, 1 << second, on_spine, is_last) else {
+                    return Ok(());
+                };
+
+                for branch in Branch::of_sides(self.items[first as usize], self.items[second as usize], target_side, self.rules, self.options)? {
+                    if (branch.total_cost as u32) < limit().min(self.total_limit()) {
+                        f(branch);
+                    }
+                }
+            }
+            _ => {
+                let mut outcome = Outcome::default();
+
+                for split in self.splits(indices) {
+                    outcome.extend(self.visit_split(indices, split, on_spine, goal, limit, f));
+                }
+
+                return outcome.into_result();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The distinct splits of the items at `indices`, where constrained items are told apart from
+    /// equal items.
+    pub(crate) fn splits(&self, indices: &[u8]) -> DistinctSplits {
+        distinct_splits(
+            &indices
+                .iter()
+                .map(|&index| (self.items[index as usize], self.constraints.is_constrained(index).then_some(index)))
+                .collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>(),
+        )
+    }
+
+    /// Calls `f` with the plans of [`visit`](Self::visit) that merge the items at `left` and `right`
+    /// of `indices` last, and tells whether there are any and why not.
+    pub(crate) fn visit_split(
+        &self,
+        indices: &[u8],
+        (left, right): (u64, u64),
+        on_spine: bool,
+        goal: Goal,
+        limit: &dyn Fn() -> u32,
+        f: &mut dyn FnMut(Branch),
+    ) -> Outcome {
+        let mut outcome = Outcome::default();
+        let limit = &|| limit().min(self.total_limit());
+        let left_indices = positions(left).map(|position| indices[position]).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+        let right_indices = positions(right).map(|position| indices[position]).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+        let is_last = indices.len() == self.items.len();
+
+        let Some(target_side) = self.constraints.target_side(set_of(&left_indices) as u64, set_of(&right_indices) as u64, on_spine, is_last) else {
+            return outcome;
+        };
+
+        let bound = &self.bounds[(set_of(&left_indices) | set_of(&right_indices)) as usize];
+        let left_bound = &self.bounds[set_of(&left_indices) as usize];
+        let right_bound = &self.bounds[set_of(&right_indices) as usize];
+        let step_cost = Bound::step_cost(bound, left_bound, right_bound);
+
+        if left_bound.cost + right_bound.cost + step_cost >= limit() {
+            self.pruned.fetch_add(1, Ordering::Relaxed);
+            outcome.found_any = true;
+            return outcome;
+        }
+
+        self.visited.fetch_add(1, Ordering::Relaxed);
+
+        let left_limit = || limit().saturating_sub(right_bound.cost + step_cost);
+        let mut left_plans = SubPlans::new(goal);
+
+        let result = self.visit(&left_indices, on_spine && target_side == TargetSide::Left, goal, &left_limit, &mut |left_branch| {
+            let left_item = match left_branch.steps.last() {
+                Some(step) => step.result,
+                None => self.items[left_indices[0] as usize],
+            };
+
+            if !self.admits(&left_plans, left_item, &left_branch) {
+                return;
+            }
+
+            let step_cost = Bound::step_cost(bound, &Bound { uses: left_item.prior_work.uses(), ..*left_bound }, right_bound);
+            let right_limit = || limit().saturating_sub(left_branch.total_cost as u32 + step_cost);
+            let mut right_plans = SubPlans::new(goal);
+
+            let result = self.visit(&right_indices, on_spine && target_side == TargetSide::Right, goal, &right_limit, &mut |right_branch| {
+                let right_item = right_branch.steps.last().unwrap().result;
+
+                if !self.admits(&right_plans, right_item, &right_branch) {
+                    return;
+                }
+
+                let new_branches = match Branch::of_sides(left_item, right_item, target_side, self.rules, self.options) {
+                    Ok(branches) => branches,
+                    Err(new_error) => {
+                        outcome.add_error(new_error);
+                        return;
+                    }
+                };
+
+                let mut merged = false;
+
+                for new_branch in new_branches {
+                    let total_cost = new_branch.total_cost as u32 + left_branch.total_cost as u32 + right_branch.total_cost as u32;
+
+                    if total_cost > u16::MAX as u32 {
+                        outcome.add_error(CombinationError::Overflow {
+                            target: new_branch.steps[0].target,
+                            sacrifice: new_branch.steps[0].sacrifice,
+                        });
+                        continue;
+                    }
+
+                    merged = true;
+                    outcome.found_any = true;
+
+                    if total_cost < limit() {
+                        f(new_branch.after(&left_branch, &right_branch).expect("the total cost fits into a u16"));
+                    }
+                }
+
+                if merged {
+                    right_plans.insert(right_item, right_branch);
+                }
+            });
+
+            if let Err(new_error) = result {
+                outcome.add_error(new_error);
+            }
+
+            left_plans.insert(left_item, left_branch);
+        });
+
+        if let Err(new_error) = result {
+            outcome.add_error(new_error);
+        }
+
+        outcome
+    }
+}
+
+impl BoundedSearch<'_> {
+    /// Whether a plan for a part of the items can still lead to a plan that meets the goal.
+    ///
+    /// Renaming in survival depends on the cost of the steps, not only on the result, and so does
+    /// the most expensive step with renaming, so then every plan is kept.
+    fn admits(&self, plans: &SubPlans, item: Item, branch: &Branch) -> bool {
+        let depends_on_steps = match plans.goal {
+            Goal::Cheapest(_) => {
+                self.options.rename && (self.rules.game_mode.cost_limit().is_some() || self.options.max_step_cost.is_some())
+            }
+            Goal::ParetoFront => self.options.rename,
+            Goal::LeastExperience => true,
+        };
+
+        depends_on_steps || plans.admits(item, branch)
+    }
+
+    /// Renames the plan if the options say so, and checks that it keeps to the budget.
+    pub(crate) fn renamed(&self, branch: Branch) -> Result<Branch, CombinationError> {
+        let branch = if self.options.rename {
+            branch.renamed_within(self.items[0], self.rules, self.options.max_step_cost)?
+        } else {
+            branch
+        };
+
+        self.options.check_budget(&branch)?;
+        Ok(branch)
+    }
+
+    /// The limit of the budget for all steps together, which no plan reaches.
+    fn total_limit(&self) -> u32 {
+        self.options.max_total_cost.map_or(u32::MAX, |limit| limit as u32 + 1)
+    }
+
+    /// The error for a search that found neither plans nor errors, as every plan was over the
+    /// budget for all steps or broke the constraints.
+    pub(crate) fn no_plan(&self) -> CombinationError {
+        match self.options.max_total_cost {
+            Some(limit) => CombinationError::TotalOverBudget { limit },
+            None => CombinationError::ConstraintsUnmet,
+        }
+    }
+
+    /// The `count` cheapest distinct plans below the limit, renamed if the options say so, ordered
+    /// like [`cheapest_branches`].
+    fn cheapest(&self, count: usize, limit: u32) -> Result<Vec<Branch>, CombinationError> {
+        let indices = (0..self.items.len() as u8).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+        let mut branches = Vec::<Branch>::with_capacity(count);
+        let branch_limit = Cell::new(limit);
+        let mut error = None;
+
+        let limit = || search_limit(branch_limit.get(), self.options);
+
+        self.visit(&indices, true, Goal::Cheapest(count), &limit, &mut |branch| {
+            let branch = match self.renamed(branch) {
+                Ok(branch) => branch,
+                Err(new_error) => {
+                    keep_relevant_error(&mut error, new_error);
+                    return;
+                }
+            };
+
+            if branch.total_cost as u32 >= branch_limit.get() || branches.contains(&branch) {
+                return;
+            }
+
+            // Plans of the same cost stay in the order they were found.
+            let index = branches.partition_point(|other| other.total_cost <= branch.total_cost);
+            branches.insert(index, branch);
+            branches.truncate(count);
+
+            if branches.len() == count {
+                branch_limit.set(branches[count - 1].total_cost as u32);
+            }
+        })?;
+
+        match error {
+            Some(error) if branches.is_empty() => Err(error),
+            _ => Ok(branches),
+        }
+    }
+
+    /// The cheapest plan whose final item is accepted, renamed if the options say so. Plans with
+    /// the same result are interchangeable, so the goal can still skip the more expensive ones.
+    fn cheapest_accepted(&self, accept: &dyn Fn(Item) -> bool) -> Result<Option<Branch>, CombinationError> {
+        let indices = (0..self.items.len() as u8).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+        let mut best = None::<Branch>;
+        let branch_limit = Cell::new(u32::MAX);
+        let mut error = None;
+
+        let limit = || search_limit(branch_limit.get(), self.options);
+
+        self.visit(&indices, true, Goal::Cheapest(1), &limit, &mut |branch| {
+            let branch = match self.renamed(branch) {
+                Ok(branch) => branch,
+                Err(new_error) => {
+                    keep_relevant_error(&mut error, new_error);
+                    return;
+                }
+            };
+
+            let result = branch.steps.last().map_or(self.items[0], |step| step.result);
+
+            if (branch.total_cost as u32) < branch_limit.get() && accept(result) {
+                branch_limit.set(branch.total_cost as u32);
+                best = Some(branch);
+            }
+        })?;
+
+        match error {
+            Some(error) if best.is_none() => Err(error),
+            _ => Ok(best),
+        }
+    }
+
+    /// The same plans as [`cheapest`](Self::cheapest), found by `threads` threads that take turns
+    /// searching the splits of all items.
+    fn cheapest_in_parallel(&self, count: usize, limit: u32, threads: NonZeroUsize) -> Result<Vec<Branch>, CombinationError> {
+        let indices = (0..self.items.len() as u8).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+
+        if indices.len() < 3 || threads.get() == 1 {
+            return self.cheapest(count, limit);
+        }
+
+        let splits = self.splits(&indices).collect::<Vec<_>>();
+        let next_split = AtomicUsize::new(0);
+        let branch_limit = AtomicU32::new(limit);
+
+        // The plans are ordered by cost, then by split and by the order in which the split found
+        // them, which is the order of the sequential search.
+        let branches = Mutex::new(Vec::<((u16, usize, usize), Branch)>::with_capacity(count));
+        let outcomes = Mutex::new((0..splits.len()).map(|_| (Outcome::default(), None)).collect::<Vec<_>>());
+
+        thread::scope(|scope| {
+            for _ in 0..threads.get().min(splits.len()) {
+                scope.spawn(|| loop {
+                    let split = next_split.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(&(left, right)) = splits.get(split) else {
+                        break;
+                    };
+
+                    let mut error = None;
+                    let mut found = 0;
+
+                    // A plan of the same cost as the last one kept can still come before it, so
+                    // only plans that cost more are skipped.
+                    let limit = || search_limit(branch_limit.load(Ordering::Relaxed), self.options);
+
+                    let outcome = self.visit_split(&indices, (left, right), true, Goal::Cheapest(count), &limit, &mut |branch| {
+                        let branch = match self.renamed(branch) {
+                            Ok(branch) => branch,
+                            Err(new_error) => {
+                                keep_relevant_error(&mut error, new_error);
+                                return;
+                            }
+                        };
+
+                        let key = (branch.total_cost, split, found);
+                        found += 1;
+
+                        if branch.total_cost as u32 >= branch_limit.load(Ordering::Relaxed) {
+                            return;
+                        }
+
+                        let mut branches = branches.lock().unwrap();
+
+                        if let Some(index) = branches.iter().position(|(_, other)| *other == branch) {
+                            if branches[index].0 < key {
+                                return;
+                            }
+
+                            branches.remove(index);
+                        }
+
+                        let index = branches.partition_point(|(other, _)| *other < key);
+                        branches.insert(index, (key, branch));
+                        branches.truncate(count);
+
+                        if branches.len() == count {
+                            branch_limit.fetch_min(branches[count - 1].0.0 as u32 + 1, Ordering::Relaxed);
+                        }
+                    });
+
+                    outcomes.lock().unwrap()[split] = (outcome, error);
+                });
+            }
+        });
+
+        let mut outcome = Outcome::default();
+        let mut error = None;
+
+        for (split_outcome, split_error) in outcomes.into_inner().unwrap() {
+            outcome.extend(split_outcome);
+
+            if let Some(new_error) = split_error {
+                keep_relevant_error(&mut error, new_error);
+            }
+        }
+
+        outcome.into_result()?;
+
+        let branches = branches.into_inner().unwrap();
+
+        match error {
+            Some(error) if branches.is_empty() => Err(error),
+            _ => Ok(branches.into_iter().map(|(_, branch)| branch).collect()),
+        }
+    }
+
+    /// The plans that are not dominated by another, renamed if the options say so, ordered like
+    /// [`pareto_branches`].
+    fn pareto_front(&self) -> Result<Vec<Branch>, CombinationError> {
+        let indices = (0..self.items.len() as u8).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+        let mut branches = Vec::<(Objectives, Branch)>::new();
+        let mut error = None;
+
+        self.visit(&indices, true, Goal::ParetoFront, &|| u32::MAX, &mut |branch| {
+            let branch = match self.renamed(branch) {
+                Ok(branch) => branch,
+                Err(new_error) => {
+                    keep_relevant_error(&mut error, new_error);
+                    return;
+                }
+            };
+
+            let objectives = Objectives::of(&branch, self.items[0]);
+
+            if branches.iter().any(|(other, _)| other.dominates(&objectives)) {
+                return;
+            }
+
+            branches.retain(|(other, _)| !objectives.dominates(other));
+            branches.push((objectives, branch));
+        })?;
+
+        if branches.is_empty() {
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+
+        branches.sort_by_key(|(objectives, _)| *objectives);
+        Ok(branches.into_iter().map(|(_, branch)| branch).collect())
+    }
+
+    /// The plan that takes the fewest points from a player at `start_level` and fewer than `limit`,
+    /// with its steps ordered by [`Branch::ordered_by_experience`].
+    fn least_experience(&self, start_level: u16, limit: u64) -> Result<Option<Branch>, CombinationError> {
+        let indices = (0..self.items.len() as u8).collect::<ArrayVec<_, MAX_SEARCH_ITEMS>>();
+        let mut best = None;
+        let mut best_points = limit;
+        let levels = Cell::new(level_limit(start_level, limit));
+        let mut error = None;
+
+        let limit = || search_limit(levels.get(), self.options);
+
+        self.visit(&indices, true, Goal::LeastExperience, &limit, &mut |branch| {
+            let branch = match self.renamed(branch) {
+                Ok(branch) => branch.ordered_by_experience(start_level),
+                Err(new_error) => {
+                    keep_relevant_error(&mut error, new_error);
+                    return;
+                }
+            };
+
+            let points = branch.experience_points(start_level);
+
+            if points < best_points {
+                best_points = points;
+                levels.set(level_limit(start_level, points));
+                best = Some(branch);
+            }
+        })?;
+
+        match error {
+            Some(error) if best.is_none() => Err(error),
+            _ => Ok(best),
+        }
+    }
+}
+
+/// Whether a search found a plan, counting plans above the limit, and the error to report if not.
+#[derive(Default)]
+pub(crate) struct Outcome {
+    found_any: bool,
+    error: Option<CombinationError>,
+}
+
+impl Outcome {
+    pub(crate) fn add_error(&mut self, error: CombinationError) {
+        keep_relevant_error(&mut self.error, error);
+    }
+
+    /// Adds the outcome of a search that comes after this one.
+    pub(crate) fn extend(&mut self, other: Outcome) {
+        self.found_any |= other.found_any;
+
+        if let Some(error) = other.error {
+            self.add_error(error);
+        }
+    }
+
+    pub(crate) fn into_result(self) -> Result<(), CombinationError> {
+        match self.error {
+            Some(error) if !self.found_any => Err(error),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// What the plans for a part of the items are needed for.
+#[derive(Copy, Clone)]
+pub(crate) enum Goal {
+    /// The given number of cheapest plans.
+    Cheapest(usize),
+    /// The plans that are not dominated by another in [`Objectives`].
+    ParetoFront,
+    /// The plan that takes the fewest experience points.
+    LeastExperience,
+}
+
+/// The criteria of [`pareto_branches`], all of which are better when lower.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Objectives {
+    total_cost: u16,
+    highest_step_cost: u16,
+    prior_work_penalty: u16,
+}
+
+impl Objectives {
+    /// The objectives of a plan, where `item` is the result if there are no steps.
+    fn of(branch: &Branch, item: Item) -> Self {
+        Self {
+            total_cost: branch.total_cost,
+            highest_step_cost: highest_step_cost(branch),
+            prior_work_penalty: branch.steps.last().map_or(item, |step| step.result).prior_work.penalty(),
+        }
+    }
+
+    /// Whether these objectives are at least as good in every way. Equal objectives dominate each other.
+    fn dominates(&self, other: &Self) -> bool {
+        self.total_cost <= other.total_cost
+            && self.highest_step_cost <= other.highest_step_cost
+            && self.prior_work_penalty <= other.prior_work_penalty
+    }
+}
+
+fn highest_step_cost(branch: &Branch) -> u16 {
+    branch.steps.iter().map(|step| step.cost).max().unwrap_or(0)
+}
+
+/// The plans found for a part of the items, by result.
+struct SubPlans {
+    goal: Goal,
+    plans: Vec<(Item, Vec<Branch>)>,
+}
+
+impl SubPlans {
+    fn new(goal: Goal) -> Self {
+        Self {
+            goal,
+            plans: Vec::new(),
+        }
+    }
+
+    /// Whether a plan can lead to a plan that meets the goal, given the plans with the same result
+    /// before it. Otherwise, every plan it leads to is not better than one it would have led to.
+    ///
+    /// For the `count` cheapest plans, this is the case if the plan is not the same as one before,
+    /// and there are less than `count` plans before it with no higher cost. For the Pareto front,
+    /// no plan before may have both no higher cost and no more expensive step.
+    fn admits(&self, item: Item, branch: &Branch) -> bool {
+        let Some((_, plans)) = self.plans.iter().find(|(result, _)| *result == item) else {
+            return true;
+        };
+
+        match self.goal {
+            Goal::Cheapest(count) => {
+                plans.partition_point(|plan| plan.total_cost <= branch.total_cost) < count && !plans.contains(branch)
+            }
+            Goal::ParetoFront => !plans.iter().any(|plan| {
+                plan.total_cost <= branch.total_cost && highest_step_cost(plan) <= highest_step_cost(branch)
+            }),
+            Goal::LeastExperience => true,
+        }
+    }
+
+    fn insert(&mut self, item: Item, branch: Branch) {
+        // Which steps take the fewest points depends on all steps of the plan, so no plan is skipped.
+        if matches!(self.goal, Goal::LeastExperience) {
+            return;
+        }
+
+        let plans = match self.plans.iter().position(|(result, _)| *result == item) {
+            Some(index) => &mut self.plans[index].1,
+            None => {
+                self.plans.push((item, Vec::new()));
+                &mut self.plans.last_mut().unwrap().1
+            }
+        };
+
+        match self.goal {
+            Goal::Cheapest(count) => {
+                let index = plans.partition_point(|plan| plan.total_cost <= branch.total_cost);
+                plans.insert(index, branch);
+                plans.truncate(count);
+            }
+            Goal::LeastExperience => unreachable!(),
+            Goal::ParetoFront => {
+                plans.retain(|plan| {
+                    plan.total_cost < branch.total_cost || highest_step_cost(plan) < highest_step_cost(&branch)
+                });
+                plans.push(branch);
+            }
+        }
+    }
+}
+
+/// The fewest total levels at which no plan can take fewer than `points` points from a player at
+/// `start_level`.
+fn level_limit(start_level: u16, points: u64) -> u32 {
+    let (mut low, mut high) = (0_u32, u16::MAX as u32 + 1);
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        if least_points(start_level, middle) >= points {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    if least_points(start_level, low) >= points {
+        low
+    } else {
+        u32::MAX
+    }
+}
+
+/// No plan that merges all of the items, at most 32 of them, is cheaper than this. Unlike the
+/// bounds of the search, it does not need the cheapest plan of every subset.
+pub(crate) fn lower_bound(items: &[Item], rules: Rules) -> u32 {
+    Bound::new(items, ((1_u64 << items.len()) - 1) as u32, None, rules).cost
+}
+
+/// The limit below which to search for plans that cost less than `limit` once renamed.
+pub(crate) fn search_limit(limit: u32, options: SearchOptions) -> u32 {
+    // Renaming adds at least one level.
+    limit.saturating_sub(options.rename as u32)
+}
+
+/// Fails if there are more items than `limit`. Returns the plan without steps if there are no items.
+pub(crate) fn check_item_count(items: &[Item], limit: usize) -> Result<Option<Branch>, CombinationError> {
+    if items.len() > limit {
+        return Err(CombinationError::TooManyItems {
+            count: items.len(),
+            limit,
+        });
+    }
+
+    Ok(items.is_empty().then(|| Branch {
+        steps: Vec::new(),
+        total_cost: 0,
+    }))
+}
+
+fn set_of(indices: &[u8]) -> u32 {
+    indices.iter().fold(0, |set, &index| set | 1 << index)
+}
+
+/// Finds the cheapest plan of [`branch_iterator`](crate::branch_iterator) without enumerating all
+/// of them. Splits of the items that cannot beat the best plan found so far are skipped, using a
+/// lower bound on the cost of merging each part.
+///
+/// Of several plans with the same cost, the first one [`branch_iterator`](crate::branch_iterator)
+/// would yield is returned. Should none of them be as cheap as the plan of
+/// [`optimal_branch`](crate::optimal_branch), that plan is returned instead.
+pub fn best_branch(items: &[Item], rules: Rules, options: SearchOptions) -> Result<Branch, CombinationError> {
+    parallel_best_branch(items, rules, options, NonZeroUsize::MIN)
+}
+
+/// Finds the cheapest plan of [`constrained_branch_iterator`](crate::constrained_branch_iterator)
+/// in the same way as [`best_branch`], so the plan keeps to the constraints. Items are given to the
+/// constraints by their position.
+pub fn constrained_best_branch(items: &[Item], rules: Rules, options: SearchOptions, constraints: &[Constraint]) -> Result<Branch, CombinationError> {
+    let empty = check_item_count(items, MAX_SEARCH_ITEMS)?;
+    let constraints = ConstraintMasks::new(constraints, items.len())?;
+
+    if let Some(branch) = empty {
+        return Ok(branch);
+    }
+
+    // The plan of the subset solver does not keep to the constraints, so it is no use as a limit.
+    let search = BoundedSearch {
+        constraints,
+        ..BoundedSearch::new(items, rules, options)
+    };
+
+    let mut branches = search.cheapest(1, u32::MAX)?;
+    branches.pop().ok_or_else(|| search.no_plan())
+}
+
+/// Finds the same plan as [`best_branch`], using `threads` threads.
+pub fn parallel_best_branch(items: &[Item], rules: Rules, options: SearchOptions, threads: NonZeroUsize) -> Result<Branch, CombinationError> {
+    if let Some(branch) = check_item_count(items, MAX_SEARCH_ITEMS)? {
+        return Ok(branch);
+    }
+
+    let search = BoundedSearch::new(items, rules, options);
+
+    // The plan of the subset solver is the cheapest of all, so it makes for a tight first limit, and
+    // is the answer if the search finds none as cheap.
+    if let Ok(optimal) = &search.optimal {
+        if let Ok(mut branches) = search.cheapest_in_parallel(1, optimal.total_cost as u32 + 1, threads) {
+            if let Some(branch) = branches.pop() {
+                return Ok(branch);
+            }
+        }
+
+        return Ok(optimal.clone());
+    }
+
+    let mut branches = search.cheapest_in_parallel(1, u32::MAX, threads)?;
+    branches.pop().ok_or_else(|| search.no_plan())
+}
+
+/// Finds the cheapest plan like [`best_branch`], among the plans whose final item is accepted.
+/// Returns `None` if there is no such plan.
+pub fn best_branch_where(
+    items: &[Item],
+    rules: Rules,
+    options: SearchOptions,
+    accept: impl Fn(Item) -> bool,
+) -> Result<Option<Branch>, CombinationError> {
+    if check_item_count(items, MAX_SEARCH_ITEMS)?.is_some() {
+        return Ok(None);
+    }
+
+    BoundedSearch::new(items, rules, options).cheapest_accepted(&accept)
+}
+
+/// Finds the `count` cheapest distinct plans of [`branch_iterator`](crate::branch_iterator), in the
+/// same way as [`best_branch`].
+///
+/// The plans are ordered by total cost, and plans of the same cost in the order
+/// [`branch_iterator`](crate::branch_iterator) yields them, which only depends on the order of
+/// the items. Fewer plans are returned if there are not as many.
+pub fn cheapest_branches(items: &[Item], rules: Rules, options: SearchOptions, count: usize) -> Result<Vec<Branch>, CombinationError> {
+    parallel_cheapest_branches(items, rules, options, count, NonZeroUsize::MIN)
+}
+
+/// Finds the same plans in the same order as [`cheapest_branches`], using `threads` threads.
+pub fn parallel_cheapest_branches(
+    items: &[Item],
+    rules: Rules,
+    options: SearchOptions,
+    count: usize,
+    threads: NonZeroUsize,
+) -> Result<Vec<Branch>, CombinationError> {
+    let empty = check_item_count(items, MAX_SEARCH_ITEMS)?;
+
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    if let Some(branch) = empty {
+        return Ok(vec![branch]);
+    }
+
+    let search = BoundedSearch::new(items, rules, options);
+    let branches = search.cheapest_in_parallel(count, u32::MAX, threads)?;
+
+    if branches.is_empty() {
+        return Err(search.no_plan());
+    }
+
+    Ok(branches)
+}
+
+/// Finds every plan of [`branch_iterator`](crate::branch_iterator) that no other plan beats in
+/// total cost, the cost of the most expensive step and the prior work penalty of the final item
+/// at once.
+///
+/// Of several plans that are equal in all three, only the first one
+/// [`branch_iterator`](crate::branch_iterator) yields is returned. The plans are ordered by total
+/// cost, then by the cost of the most expensive step.
+pub fn pareto_branches(items: &[Item], rules: Rules, options: SearchOptions) -> Result<Vec<Branch>, CombinationError> {
+    if let Some(branch) = check_item_count(items, MAX_SEARCH_ITEMS)? {
+        return Ok(vec![branch]);
+    }
+
+    let search = BoundedSearch::new(items, rules, options);
+    let branches = search.pareto_front()?;
+
+    if branches.is_empty() {
+        return Err(search.no_plan());
+    }
+
+    Ok(branches)
+}
+
+/// Finds the plan of [`branch_iterator`](crate::branch_iterator) that takes the fewest experience
+/// points from a player at `start_level`, in the same way as [`best_branch`]. The steps are ordered
+/// by [`Branch::ordered_by_experience`].
+///
+/// Levels are worth more points the higher the player is, so this is not always the cheapest plan.
+/// Of several plans that take the same points, the first one
+/// [`branch_iterator`](crate::branch_iterator) would yield is returned.
+pub fn least_experience_branch(items: &[Item], rules: Rules, options: SearchOptions, start_level: u16) -> Result<Branch, CombinationError> {
+    if let Some(branch) = check_item_count(items, MAX_SEARCH_ITEMS)? {
+        return Ok(branch);
+    }
+
+    let search = BoundedSearch::new(items, rules, options);
+
+    if let Ok(branch) = &search.optimal {
+        let points = branch.clone().ordered_by_experience(start_level).experience_points(start_level);
+
+        if let Ok(Some(branch)) = search.least_experience(start_level, points + 1) {
+            return Ok(branch);
+        }
+    }
+
+    search.least_experience(start_level, u64::MAX)?.ok_or_else(|| search.no_plan())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use crate::anvil::{branch_iterator, Branch, CombinationError, Item, SearchOptions};
+    use crate::anytime::{anytime_best_branch, SearchControl};
+    use crate::anvil::tests::{book, collect, item};
+    use crate::optimal::optimal_branch;
+    use crate::enchantments::EnchantmentKind;
+    use crate::items::ItemKind;
+    use crate::rules::{Edition, GameMode, Rules};
+    use crate::search::{best_branch, best_branch_where, cheapest_branches, least_experience_branch, parallel_best_branch, parallel_cheapest_branches, pareto_branches};
+
+    fn item_sets() -> [Vec<Item>; 3] {
+        [
+            vec![
+                item(ItemKind::Boots, &[(EnchantmentKind::DepthStrider, 3)], 1),
+                book(EnchantmentKind::Protection, 4),
+                book(EnchantmentKind::FeatherFalling, 4),
+                book(EnchantmentKind::Thorns, 3),
+                book(EnchantmentKind::Unbreaking, 3),
+                book(EnchantmentKind::Mending, 1),
+                book(EnchantmentKind::SoulSpeed, 3),
+            ],
+            vec![
+                item(ItemKind::Sword, &[(EnchantmentKind::Sharpness, 4)], 0),
+                book(EnchantmentKind::Sharpness, 4),
+                book(EnchantmentKind::Smite, 5),
+                book(EnchantmentKind::Looting, 3),
+                book(EnchantmentKind::Unbreaking, 3),
+                book(EnchantmentKind::Power, 1),
+            ],
+            vec![
+                item(ItemKind::Pickaxe, &[], 0),
+                book(EnchantmentKind::Efficiency, 4),
+                book(EnchantmentKind::Efficiency, 4),
+                book(EnchantmentKind::Unbreaking, 3),
+                book(EnchantmentKind::Unbreaking, 3),
+                book(EnchantmentKind::Fortune, 3),
+            ],
+        ]
+    }
+
+    fn rules_and_options() -> impl Iterator<Item = (Rules, SearchOptions)> {
+        [Rules::default(), Rules::new(Edition::Java, GameMode::Creative)]
+            .into_iter()
+            .flat_map(|rules| [(rules, SearchOptions::default()), (rules, SearchOptions { rename: true, ..SearchOptions::default() })])
+    }
+
+    #[test]
+    fn matches_enumeration() {
+        for items in item_sets() {
+            for (rules, options) in rules_and_options() {
+                let expected = collect(&items, rules, options)
+                    .into_iter()
+                    .reduce(|best, branch| if branch.total_cost < best.total_cost { branch } else { best })
+                    .unwrap();
+
+                assert_eq!(best_branch(&items, rules, options).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn both_orders() {
+        // Putting Frost Walker II onto the boots with Unbreaking costs more than the other way round,
+        // but only the boots that keep Frost Walker II lead to the cheapest plan.
+        let items = [
+            item(ItemKind::Boots, &[(EnchantmentKind::Unbreaking, 1)], 0),
+            item(ItemKind::Boots, &[(EnchantmentKind::FrostWalker, 2)], 0),
+            item(ItemKind::Book, &[(EnchantmentKind::FrostWalker, 2), (EnchantmentKind::FeatherFalling, 4)], 1),
+            item(ItemKind::Book, &[(EnchantmentKind::Protection, 2), (EnchantmentKind::FrostWalker, 1)], 0),
+            item(ItemKind::Book, &[(EnchantmentKind::FireProtection, 3), (EnchantmentKind::FeatherFalling, 2)], 0),
+        ];
+
+        let rules = Rules::default();
+        let options = SearchOptions::default();
+        let cheapest = collect(&items, rules, options).into_iter().map(|branch| branch.total_cost).min();
+
+        assert_eq!(optimal_branch(&items, rules, options).unwrap().total_cost, 27);
+        assert_eq!(cheapest, Some(27));
+        assert_eq!(best_branch(&items, rules, options).unwrap().total_cost, 27);
+        assert_eq!(parallel_best_branch(&items, rules, options, NonZeroUsize::new(4).unwrap()).unwrap().total_cost, 27);
+        assert_eq!(cheapest_branches(&items, rules, options, 3).unwrap()[0].total_cost, 27);
+        assert_eq!(pareto_branches(&items, rules, options).unwrap()[0].total_cost, 27);
+    }
+
+    #[test]
+    fn accepted_matches_enumeration() {
+        let items = &item_sets()[1];
+        let accept = |item: Item| item.prior_work.uses() >= 4;
+
+        for (rules, options) in rules_and_options() {
+            let expected = collect(items, rules, options)
+                .into_iter()
+                .filter(|branch| accept(branch.steps.last().unwrap().result))
+                .reduce(|best, branch| if branch.total_cost < best.total_cost { branch } else { best });
+
+            assert!(expected.is_some());
+            assert_eq!(best_branch_where(items, rules, options, accept).unwrap(), expected);
+            assert_eq!(best_branch_where(items, rules, options, |_| false).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn budget() {
+        for items in item_sets() {
+            for (rules, options) in rules_and_options() {
+                let best = best_branch(&items, rules, options).unwrap();
+                let highest_step_cost = best.steps.iter().map(|step| step.cost).max().unwrap();
+
+                let options = SearchOptions {
+                    max_step_cost: Some(highest_step_cost - 1),
+                    ..options
+                };
+
+                let expected = branch_iterator(&items, rules, options)
+                    .filter_map(Result::ok)
+                    .reduce(|best, branch| if branch.total_cost < best.total_cost { branch } else { best });
+
+                let Some(expected) = expected else {
+                    assert!(matches!(best_branch(&items, rules, options), Err(CombinationError::StepOverBudget { .. })));
+                    continue;
+                };
+
+                let branch = best_branch(&items, rules, options).unwrap();
+
+                assert_eq!(branch, expected);
+                assert!(branch.total_cost >= best.total_cost);
+                assert!(branch.steps.iter().all(|step| step.cost < highest_step_cost));
+
+                let total_limit = branch.total_cost - 1;
+
+                assert_eq!(
+                    best_branch(&items, rules, SearchOptions { max_total_cost: Some(total_limit), ..options }),
+                    Err(CombinationError::TotalOverBudget { limit: total_limit })
+                );
+
+                let cheapest = cheapest_branches(&items, rules, SearchOptions { max_total_cost: Some(branch.total_cost), ..options }, 3).unwrap();
+                assert!(cheapest.iter().all(|cheap| cheap.total_cost == branch.total_cost));
+            }
+
+            assert!(matches!(
+                best_branch(&items, Rules::default(), SearchOptions { max_step_cost: Some(2), ..SearchOptions::default() }),
+                Err(CombinationError::StepOverBudget { limit: 2, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn cheapest_match_enumeration() {
+        for items in item_sets() {
+            for (rules, options) in rules_and_options() {
+                let mut branches = collect(&items, rules, options);
+                branches.sort_by_key(|branch| branch.total_cost);
+
+                let mut expected = Vec::new();
+
+                for branch in branches {
+                    if !expected.contains(&branch) {
+                        expected.push(branch);
+                    }
+                }
+
+                expected.truncate(6);
+
+                assert_eq!(cheapest_branches(&items, rules, options, 6).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn more_than_ten_items() {
+        let mut items = vec![item(ItemKind::Helmet, &[], 0)];
+
+        items.extend([
+            EnchantmentKind::Protection,
+            EnchantmentKind::Unbreaking,
+            EnchantmentKind::Mending,
+            EnchantmentKind::Respiration,
+            EnchantmentKind::AquaAffinity,
+            EnchantmentKind::Thorns,
+            EnchantmentKind::CurseOfBinding,
+            EnchantmentKind::CurseOfVanishing,
+        ].map(|kind| book(kind, 1)));
+
+        items.extend([book(EnchantmentKind::Protection, 1), book(EnchantmentKind::Unbreaking, 1), book(EnchantmentKind::Respiration, 1)]);
+
+        // A complete search takes too long, so it is stopped after the first split of all items.
+        let rules = Rules::new(Edition::Java, GameMode::Creative);
+        let control = SearchControl::new();
+        let result = anytime_best_branch(&items, rules, SearchOptions::default(), &control, &mut |_| control.cancel()).unwrap();
+        let branch = result.branch.unwrap();
+
+        assert_eq!(branch.steps.len(), items.len() - 1);
+        assert!(branch.total_cost <= optimal_branch(&items, rules, SearchOptions::default()).unwrap().total_cost);
+        assert_eq!(branch_iterator(&items, rules, SearchOptions::default()).next().unwrap().unwrap().steps.len(), items.len() - 1);
+        assert!(matches!(
+            best_branch(&vec![book(EnchantmentKind::Unbreaking, 1); 21], rules, SearchOptions::default()),
+            Err(CombinationError::TooManyItems { count: 21, limit: 20 })
+        ));
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let threads = NonZeroUsize::new(4).unwrap();
+
+        for items in item_sets() {
+            for (rules, options) in rules_and_options() {
+                assert_eq!(parallel_best_branch(&items, rules, options, threads), best_branch(&items, rules, options));
+
+                assert_eq!(
+                    parallel_cheapest_branches(&items, rules, options, 10, threads),
+                    cheapest_branches(&items, rules, options, 10),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pareto_front_matches_enumeration() {
+        let mut trade_offs = 0;
+
+        for items in item_sets() {
+            for (rules, options) in rules_and_options() {
+                let objectives = |branch: &Branch| (
+                    branch.total_cost,
+                    branch.steps.iter().map(|step| step.cost).max().unwrap(),
+                    branch.steps.last().unwrap().result.prior_work.penalty(),
+                );
+
+                let branches = collect(&items, rules, options);
+                let mut expected = Vec::new();
+
+                for branch in &branches {
+                    let (total_cost, highest_step_cost, penalty) = objectives(branch);
+
+                    let dominated = branches.iter().any(|other| {
+                        let (other_total_cost, other_highest_step_cost, other_penalty) = objectives(other);
+
+                        other_total_cost <= total_cost
+                            && other_highest_step_cost <= highest_step_cost
+                            && other_penalty <= penalty
+                            && objectives(other) != objectives(branch)
+                    });
+
+                    if !dominated && !expected.iter().any(|other| objectives(other) == objectives(branch)) {
+                        expected.push(branch.clone());
+                    }
+                }
+
+                expected.sort_by_key(objectives);
+
+                let front = pareto_branches(&items, rules, options).unwrap();
+
+                assert_eq!(front, expected);
+                trade_offs += front.len() - 1;
+            }
+        }
+
+        assert!(trade_offs > 0);
+    }
+
+    #[test]
+    fn least_experience_matches_enumeration() {
+        let mut saves_points = false;
+
+        for items in item_sets() {
+            for (rules, options) in rules_and_options() {
+                let branches = collect(&items, rules, options);
+
+                for start_level in [0, 30] {
+                    let cheapest = best_branch(&items, rules, options).unwrap();
+
+                    let expected = branches
+                        .iter()
+                        .map(|branch| branch.clone().ordered_by_experience(start_level))
+                        .reduce(|best, branch| {
+                            if branch.experience_points(start_level) < best.experience_points(start_level) {
+                                branch
+                            } else {
+                                best
+                            }
+                        })
+                        .unwrap();
+
+                    let branch = least_experience_branch(&items, rules, options, start_level).unwrap();
+
+                    assert_eq!(branch, expected);
+                    saves_points |= branch.experience_points(start_level) < cheapest.experience_points(start_level);
+                }
+            }
+        }
+
+        assert!(saves_points);
+    }
+}
@@ -0,0 +1,177 @@
+use arrayvec::ArrayVec;
+use crate::dist::{dist, MAX_ITEMS};
+
+/// The most items whose splits can be listed, as each side of a split is a bit mask. This is only
+/// the width of the mask: listing every plan is impractical long before, beyond about 11 items.
+pub const MAX_SPLIT_ITEMS: usize = u64::BITS as usize;
+
+/// Every way to split `count` items into two non-empty parts, as bit masks of the positions on the
+/// left and on the right, in the order of `DIST`: by the subset on the left, which never has more
+/// items than the right, so parts of equal size appear in both orders.
+///
+/// The splits come from `DIST` if it is large enough, otherwise they are generated on demand.
+pub(crate) fn splits(count: usize) -> Splits {
+    assert!(count <= MAX_SPLIT_ITEMS, "cannot split {count} items");
+
+    if (3..=MAX_ITEMS).contains(&count) {
+        Splits::Table {
+            all: u64::MAX >> (u64::BITS as usize - count),
+            lefts: dist(count).iter(),
+        }
+    } else {
+        Splits::generated(count)
+    }
+}
+
+pub(crate) enum Splits {
+    Table {
+        all: u64,
+        lefts: std::slice::Iter<'static, u32>,
+    },
+    Generated {
+        count: u32,
+        /// The next subset of the items, where the first item is the highest bit.
+        next: u128,
+    },
+}
+
+impl Splits {
+    fn generated(count: usize) -> Self {
+        Splits::Generated {
+            count: count as u32,
+            next: 1,
+        }
+    }
+}
+
+impl Iterator for Splits {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Splits::Table { all, lefts } => {
+                let left = *lefts.next()? as u64;
+                Some((left, *all & !left))
+            }
+            Splits::Generated { count, next } => {
+                while *next < 1 << *count {
+                    let subset = *next as u64;
+                    *next += 1;
+
+                    if subset.count_ones() * 2 <= *count {
+                        let left = subset.reverse_bits() >> (u64::BITS - *count);
+                        let all = u64::MAX >> (u64::BITS - *count);
+                        return Some((left, all & !left));
+                    }
+                }
+
+                None
+            }
+        }
+    }
+}
+
+/// The [`splits`] of the items that differ by more than which of some equal items are on which
+/// side, as swapping equal items between the sides leads to the same plans.
+pub(crate) fn distinct_splits<T: PartialEq>(items: &[T]) -> DistinctSplits {
+    let equal = items
+        .iter()
+        .map(|item| items.iter().enumerate().filter(|(_, other)| *other == item).fold(0, |mask, (position, _)| mask | 1 << position))
+        .collect::<ArrayVec<u64, MAX_SPLIT_ITEMS>>();
+
+    DistinctSplits {
+        splits: splits(items.len()),
+        has_equal: equal.iter().any(|mask| mask.count_ones() > 1),
+        equal,
+    }
+}
+
+pub(crate) struct DistinctSplits {
+    splits: Splits,
+    /// For each item, the positions of the items equal to it.
+    equal: ArrayVec<u64, MAX_SPLIT_ITEMS>,
+    has_equal: bool,
+}
+
+impl Iterator for DistinctSplits {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.has_equal {
+            return self.splits.next();
+        }
+
+        let equal = &self.equal;
+        self.splits.find(|&(left, _)| is_first(left, equal))
+    }
+}
+
+/// Whether `left` has the last positions of every group of equal items on it, which makes it the
+/// first of the equivalent splits to appear.
+fn is_first(left: u64, equal: &[u64]) -> bool {
+    let mut first = 0_u64;
+
+    for position in positions(left) {
+        let remaining = equal[position] & !first;
+        first |= 1 << (u64::BITS - 1 - remaining.leading_zeros());
+    }
+
+    first == left
+}
+
+/// The positions in a mask, from the lowest.
+pub(crate) fn positions(mut mask: u64) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if mask == 0 {
+            None
+        } else {
+            let position = mask.trailing_zeros() as usize;
+            mask &= mask - 1;
+            Some(position)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dist::{dist, MAX_ITEMS};
+    use crate::splits::{distinct_splits, positions, splits, Splits};
+
+    #[test]
+    fn generated_match_dist() {
+        assert_eq!(dist(3), [0b100, 0b010, 0b001]);
+        assert_eq!(dist(10).len(), 10 + 45 + 120 + 210 + 252);
+
+        for count in 3..=MAX_ITEMS {
+            assert!(splits(count).eq(Splits::generated(count)));
+        }
+
+        // Every non-empty subset of at most 8 of the 17 items is on the left once.
+        assert_eq!(splits(17).count(), (1 << 16) - 1);
+        assert!(splits(17).all(|(left, right)| left & right == 0 && left | right == (1 << 17) - 1));
+    }
+
+    #[test]
+    fn distinct() {
+        let items = ['a', 'a', 'b', 'a', 'b'];
+        let sides = |(left, right): (u64, u64)| {
+            let side = |mask: u64| {
+                let mut side = positions(mask).map(|position| items[position]).collect::<Vec<_>>();
+                side.sort();
+                side
+            };
+
+            (side(left), side(right))
+        };
+
+        let mut all = splits(items.len()).map(sides).collect::<Vec<_>>();
+        all.sort();
+        all.dedup();
+
+        let mut distinct = distinct_splits(&items).map(sides).collect::<Vec<_>>();
+        distinct.sort();
+
+        assert_eq!(distinct, all);
+        assert!(distinct_splits(&[1, 2, 3, 4]).eq(splits(4)));
+    }
+}